@@ -0,0 +1,128 @@
+use atom_syndication::{
+    CategoryBuilder, ContentBuilder, EntryBuilder, Feed, FeedBuilder, Generator, LinkBuilder,
+    Person, PersonBuilder, Text,
+};
+use chrono::Utc;
+
+use crate::{author::Author, FeedItem, RssConf};
+
+// Function to turn an author into an Atom person
+fn person(author: Author) -> Person {
+    PersonBuilder::default()
+        .name(author.name)
+        .email(author.email)
+        .uri(author.url)
+        .build()
+}
+
+// Function to turn a feed item into an Atom entry
+fn entry(item: FeedItem) -> atom_syndication::Entry {
+    let updated = item.pub_date.fixed_offset();
+
+    let mut links = vec![LinkBuilder::default()
+        .href(item.link)
+        .rel("alternate")
+        .build()];
+    if let Some(enclosure) = item.enclosure {
+        links.push(
+            LinkBuilder::default()
+                .href(enclosure.url)
+                .rel("enclosure")
+                .mime_type(Some(enclosure.mime_type))
+                .length(Some(enclosure.length.to_string()))
+                .build(),
+        );
+    }
+
+    EntryBuilder::default()
+        .id(item.guid.value)
+        .title(Text::plain(item.title))
+        .updated(updated)
+        .published(Some(updated))
+        .authors(item.authors.into_iter().map(person).collect::<Vec<_>>())
+        .links(links)
+        .summary(item.description.map(Text::html))
+        .content(item.content.map(|html| {
+            ContentBuilder::default()
+                .value(Some(html))
+                .content_type(Some(String::from("html")))
+                .build()
+        }))
+        .categories(
+            item.categories
+                .into_iter()
+                .map(|category| {
+                    CategoryBuilder::default()
+                        .term(category.name)
+                        .scheme(category.domain)
+                        .build()
+                })
+                .collect::<Vec<_>>(),
+        )
+        .build()
+}
+
+// Function to build the Atom feed from sorted feed items, with the default
+// author already looked up in the author registry
+pub(crate) fn build_feed(
+    items: Vec<FeedItem>,
+    default_author: Option<&Author>,
+    rss_conf: &RssConf,
+) -> Feed {
+    // Atom requires `updated`; use the newest item, or now for an empty feed
+    let updated = items
+        .iter()
+        .map(|item| item.pub_date)
+        .max()
+        .unwrap_or_else(Utc::now)
+        .fixed_offset();
+
+    let mut links = vec![LinkBuilder::default()
+        .href(rss_conf.link.as_str())
+        .rel("alternate")
+        .build()];
+    if let Some(self_link) = &rss_conf.self_link {
+        links.push(
+            LinkBuilder::default()
+                .href(self_link.as_str())
+                .rel("self")
+                .mime_type(Some(String::from("application/atom+xml")))
+                .build(),
+        );
+    }
+
+    // RFC 4287 requires an author on every entry or on the feed, so entries
+    // without one fall back to a feed author named after the feed
+    let needs_author = items.iter().any(|item| item.authors.is_empty());
+    let authors = match default_author {
+        Some(author) => vec![person(author.clone())],
+        None if needs_author => vec![PersonBuilder::default()
+            .name(rss_conf.title.as_str())
+            .build()],
+        None => Vec::new(),
+    };
+
+    FeedBuilder::default()
+        .id(rss_conf.feed_id.as_deref().unwrap_or(&rss_conf.link))
+        .title(Text::plain(rss_conf.title.as_str()))
+        .subtitle(Some(Text::plain(rss_conf.description.as_str())))
+        .updated(updated)
+        .links(links)
+        .authors(authors)
+        .categories(
+            rss_conf
+                .categories
+                .iter()
+                .map(|term| CategoryBuilder::default().term(term.as_str()).build())
+                .collect::<Vec<_>>(),
+        )
+        .rights(rss_conf.copyright.as_deref().map(Text::plain))
+        .generator(rss_conf.generator.as_ref().map(|value| Generator {
+            value: value.clone(),
+            ..Default::default()
+        }))
+        .logo(rss_conf.image.as_ref().map(|image| image.url.clone()))
+        .lang(rss_conf.language.clone())
+        .entries(items.into_iter().map(entry).collect::<Vec<_>>())
+        .build()
+}
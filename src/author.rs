@@ -0,0 +1,135 @@
+use serde::Deserialize;
+use std::{collections::BTreeMap, fs, path::Path};
+
+use crate::MdrssError;
+
+// Author entry as written in the registry file
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RegistryEntry {
+    name: String,
+    email: Option<String>,
+    url: Option<String>,
+    avatar: Option<String>,
+}
+
+// Struct to hold an item author. Without a registry the handle and the name
+// are both the front matter value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Author {
+    pub(crate) handle: String,
+    pub(crate) name: String,
+    pub(crate) email: Option<String>,
+    pub(crate) url: Option<String>,
+    pub(crate) avatar: Option<String>,
+}
+
+impl Author {
+    // Function to create an author known only by name
+    pub(crate) fn from_name(name: String) -> Self {
+        Author {
+            handle: name.clone(),
+            name,
+            email: None,
+            url: None,
+            avatar: None,
+        }
+    }
+
+    // Function to format the author as RSS expects it, `email (Name)`, or
+    // just the name when the email address is unknown
+    pub(crate) fn rss(&self) -> String {
+        match &self.email {
+            Some(email) => format!("{} ({})", email, self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+// Authors by handle, loaded from `RssConf::authors_file`
+#[derive(Debug, Default)]
+pub(crate) struct AuthorRegistry {
+    entries: Option<BTreeMap<String, RegistryEntry>>,
+}
+
+impl AuthorRegistry {
+    // Function to load the registry file; no file means no registry
+    pub(crate) fn load(path: Option<&Path>) -> Result<Self, MdrssError> {
+        let Some(path) = path else {
+            return Ok(AuthorRegistry::default());
+        };
+        let content = fs::read_to_string(path).map_err(|source| MdrssError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let entries = serde_yaml::from_str(&content).map_err(|source| MdrssError::Yaml {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(AuthorRegistry {
+            entries: Some(entries),
+        })
+    }
+
+    // Function to look up the handles of the markdown file at `path` in the
+    // registry, if there is one
+    pub(crate) fn resolve(&self, authors: &mut [Author], path: &Path) -> Result<(), MdrssError> {
+        let Some(entries) = &self.entries else {
+            return Ok(());
+        };
+        for author in authors {
+            let entry = entries
+                .get(&author.handle)
+                .ok_or_else(|| MdrssError::UnknownAuthor {
+                    path: path.to_path_buf(),
+                    handle: author.handle.clone(),
+                })?;
+            author.name = entry.name.clone();
+            author.email = entry.email.clone();
+            author.url = entry.url.clone();
+            author.avatar = entry.avatar.clone();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let registry_path = temp_dir.path().join("authors.yaml");
+        fs::write(
+            &registry_path,
+            "alice:\n  name: Alice Smith\n  email: alice@example.com\n  url: https://alice.example.com\n",
+        )
+        .unwrap();
+        let registry = AuthorRegistry::load(Some(&registry_path)).unwrap();
+
+        let mut authors = vec![Author::from_name(String::from("alice"))];
+        registry
+            .resolve(&mut authors, Path::new("post.md"))
+            .unwrap();
+        assert_eq!(authors[0].handle, "alice");
+        assert_eq!(authors[0].rss(), "alice@example.com (Alice Smith)");
+        assert_eq!(authors[0].url.as_deref(), Some("https://alice.example.com"));
+
+        let mut authors = vec![Author::from_name(String::from("mallory"))];
+        let err = registry
+            .resolve(&mut authors, Path::new("post.md"))
+            .unwrap_err();
+        assert!(matches!(err, MdrssError::UnknownAuthor { handle, .. } if handle == "mallory"));
+    }
+
+    #[test]
+    fn test_resolve_without_registry() {
+        let registry = AuthorRegistry::load(None).unwrap();
+        let mut authors = vec![Author::from_name(String::from("Jane Doe"))];
+        registry
+            .resolve(&mut authors, Path::new("post.md"))
+            .unwrap();
+        assert_eq!(authors[0].rss(), "Jane Doe");
+    }
+}
@@ -0,0 +1,151 @@
+use clap::{Parser, ValueEnum};
+use mdrss::{FeedFormat, RssConf, RssReport};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+// Exit codes besides success (0) and clap's usage errors (2)
+const EXIT_FAILURE: u8 = 1;
+const EXIT_CHECK_FAILED: u8 = 3;
+
+/// Generate an RSS, Atom or JSON feed from a directory of markdown files.
+///
+/// Options are read from `mdrss.toml` (or `mdrss.yaml`) in the input
+/// directory and `MDRSS_*` environment variables; flags take precedence.
+///
+/// Exit codes: 0 on success, 1 if the feed could not be generated, 2 on
+/// invalid usage and 3 if `--check` found markdown files that failed to parse.
+#[derive(Debug, Parser)]
+#[command(name = "mdrss", version)]
+struct Cli {
+    /// Directory containing the markdown files
+    #[arg(short, long, default_value = ".")]
+    input: PathBuf,
+
+    /// Output file for the feed; `-` writes to stdout
+    #[arg(short, long, default_value = "-")]
+    output: PathBuf,
+
+    /// Title of the feed
+    #[arg(long)]
+    title: Option<String>,
+
+    /// URL of the website the feed belongs to
+    #[arg(long)]
+    link: Option<String>,
+
+    /// Description of the feed
+    #[arg(long)]
+    description: Option<String>,
+
+    /// Custom front matter delimiter, besides `---`
+    #[arg(long)]
+    delimiter: Option<String>,
+
+    /// Format of the feed
+    #[arg(short, long, value_enum)]
+    format: Option<Format>,
+
+    /// Maximum number of items in the feed
+    #[arg(short, long)]
+    limit: Option<usize>,
+
+    /// Only check the markdown files and exit with status 3 if any fail to parse
+    #[arg(long)]
+    check: bool,
+}
+
+// Command-line spelling of `FeedFormat`
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Rss,
+    Atom,
+    Json,
+}
+
+impl From<Format> for FeedFormat {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Rss => FeedFormat::Rss,
+            Format::Atom => FeedFormat::Atom,
+            Format::Json => FeedFormat::Json,
+        }
+    }
+}
+
+impl Cli {
+    // Function to build the configuration from the config file, environment and flags
+    fn rss_conf(&self) -> mdrss::Result<RssConf> {
+        let mut rss_conf = RssConf::load(&self.input)?;
+        if let Some(title) = &self.title {
+            rss_conf.title = title.clone();
+        }
+        if let Some(link) = &self.link {
+            rss_conf.link = link.clone();
+        }
+        if let Some(description) = &self.description {
+            rss_conf.description = description.clone();
+        }
+        if let Some(delimiter) = &self.delimiter {
+            rss_conf.delimiter = delimiter.clone();
+        }
+        if let Some(format) = self.format {
+            rss_conf.format = format.into();
+        }
+        if let Some(limit) = self.limit {
+            rss_conf.max_items = Some(limit);
+        }
+        Ok(rss_conf)
+    }
+}
+
+// Function to generate the feed, or only parse the markdown files in check mode
+fn run(cli: &Cli) -> mdrss::Result<RssReport> {
+    // A missing input directory would otherwise produce an empty feed
+    fs::read_dir(&cli.input).map_err(|source| mdrss::MdrssError::Io {
+        path: cli.input.clone(),
+        source,
+    })?;
+    let rss_conf = cli.rss_conf()?;
+
+    if cli.check {
+        mdrss::write_to(&cli.input, &rss_conf, io::sink())
+    } else if cli.output == Path::new("-") {
+        mdrss::write_to(&cli.input, &rss_conf, io::stdout().lock())
+    } else {
+        // Render into memory first so that a failed run leaves an existing
+        // output file untouched
+        let mut feed = Vec::new();
+        let report = mdrss::write_to(&cli.input, &rss_conf, &mut feed)?;
+        fs::write(&cli.output, feed).map_err(|source| mdrss::MdrssError::Io {
+            path: cli.output.clone(),
+            source,
+        })?;
+        Ok(report)
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let report = match run(&cli) {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::from(EXIT_FAILURE);
+        }
+    };
+
+    let level = if cli.check { "error" } else { "warning" };
+    for failure in &report.failures {
+        eprintln!("{level}: {failure}");
+    }
+
+    if cli.check && !report.is_clean() {
+        ExitCode::from(EXIT_CHECK_FAILED)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
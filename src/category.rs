@@ -0,0 +1,122 @@
+use serde::{de, Deserialize, Deserializer};
+use std::collections::BTreeMap;
+
+// Tag as written in front matter: a plain name or a `{name, domain}` object.
+// Names may be any scalar, so that `tags: [2023, rust]` keeps the year.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum RawCategory {
+    Name(#[serde(deserialize_with = "scalar_name")] String),
+    Full {
+        #[serde(deserialize_with = "scalar_name")]
+        name: String,
+        domain: Option<String>,
+    },
+}
+
+// Function to deserialize a string, number or boolean as a tag name
+fn scalar_name<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    match serde_yaml::Value::deserialize(deserializer)? {
+        serde_yaml::Value::String(name) => Ok(name),
+        serde_yaml::Value::Number(number) => Ok(number.to_string()),
+        serde_yaml::Value::Bool(flag) => Ok(flag.to_string()),
+        _ => Err(de::Error::custom("expected a tag name")),
+    }
+}
+
+// Struct to hold a normalized item category
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Category {
+    pub(crate) name: String,
+    pub(crate) domain: Option<String>,
+}
+
+// Function to lowercase a tag name and collapse its whitespace
+pub(crate) fn normalize(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+// Function to normalize front matter tags and rename them through the tag
+// map, dropping empty and duplicate tags while keeping their order
+pub(crate) fn categories(
+    raw: Vec<RawCategory>,
+    tag_map: &BTreeMap<String, String>,
+) -> Vec<Category> {
+    let mut categories: Vec<Category> = Vec::new();
+    for raw in raw {
+        let (name, domain) = match raw {
+            RawCategory::Name(name) => (name, None),
+            RawCategory::Full { name, domain } => (name, domain),
+        };
+        let name = normalize(&name);
+        if name.is_empty() {
+            continue;
+        }
+        let name = tag_map
+            .iter()
+            .find(|(from, _)| normalize(from) == name)
+            .map_or(name, |(_, to)| to.clone());
+        let category = Category { name, domain };
+        if !categories.contains(&category) {
+            categories.push(category);
+        }
+    }
+    categories
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("  Rust   Lang\t"), "rust lang");
+        assert_eq!(normalize("WEB"), "web");
+    }
+
+    #[test]
+    fn test_raw_category_scalar_names() {
+        let raw: Vec<RawCategory> =
+            serde_yaml::from_str("[2023, rust, true, {name: 1.5, domain: versions}]").unwrap();
+        assert_eq!(
+            categories(raw, &BTreeMap::new())
+                .into_iter()
+                .map(|category| category.name)
+                .collect::<Vec<_>>(),
+            ["2023", "rust", "true", "1.5"]
+        );
+        assert!(serde_yaml::from_str::<Vec<RawCategory>>("[[nested]]").is_err());
+    }
+
+    #[test]
+    fn test_categories() {
+        let raw = vec![
+            RawCategory::Name(String::from("Rust")),
+            RawCategory::Name(String::from(" rust ")),
+            RawCategory::Name(String::from("RustLang")),
+            RawCategory::Name(String::from("  ")),
+            RawCategory::Full {
+                name: String::from("Web  Dev"),
+                domain: Some(String::from("https://example.com/tags")),
+            },
+        ];
+        let tag_map = BTreeMap::from([(String::from("rustlang"), String::from("rust"))]);
+
+        assert_eq!(
+            categories(raw, &tag_map),
+            [
+                Category {
+                    name: String::from("rust"),
+                    domain: None,
+                },
+                Category {
+                    name: String::from("web dev"),
+                    domain: Some(String::from("https://example.com/tags")),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,830 @@
+use chrono::{DateTime, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::{
+    de::{self, DeserializeOwned},
+    Deserialize, Deserializer,
+};
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{MdrssError, Result};
+
+// Configuration files looked up in the markdown directory, in order
+const CONFIG_FILE_NAMES: [&str; 3] = ["mdrss.toml", "mdrss.yaml", "mdrss.yml"];
+
+// Prefix of environment variables overriding configuration options
+const ENV_PREFIX: &str = "MDRSS_";
+
+// Last hour of the day accepted in `RssConf::skip_hours`
+const MAX_SKIP_HOUR: u8 = 23;
+
+/// Output format of the generated feed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedFormat {
+    /// RSS 2.0
+    #[default]
+    Rss,
+    /// Atom 1.0
+    Atom,
+    /// JSON Feed 1.1
+    Json,
+}
+
+/// How the unique identifier of each item (the RSS `<guid>`, Atom `<id>` and
+/// JSON Feed `id`) is derived. A `guid` front matter field always takes
+/// precedence.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuidStrategy {
+    /// The item URL, which RSS treats as a permalink.
+    #[default]
+    Permalink,
+    /// A `urn:uuid:` name-based UUID of the file's path relative to the
+    /// markdown directory, independent of the post's content and URL.
+    PathHash,
+}
+
+/// What each feed item carries besides its front matter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemContent {
+    /// Only the front matter `description`.
+    #[default]
+    Summary,
+    /// The `description` plus the markdown body rendered to HTML, emitted as
+    /// `content:encoded` in RSS.
+    Full,
+}
+
+/// Grouping of items into the sub-feeds written by
+/// [`generate_sub_feeds`](crate::generate_sub_feeds).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubFeedKind {
+    /// One feed per tag, in the `tags` directory.
+    Tags,
+    /// One feed per author, in the `authors` directory.
+    Authors,
+    /// One feed per top-level subdirectory of the markdown directory, in the
+    /// `sections` directory.
+    Sections,
+}
+
+/// Image displayed with the feed, such as a logo.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[non_exhaustive]
+pub struct ChannelImage {
+    /// URL of the image.
+    pub url: String,
+    /// Alt text of the image; defaults to the feed title.
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Link target of the image; defaults to the feed link.
+    #[serde(default)]
+    pub link: Option<String>,
+    /// Width of the image in pixels.
+    #[serde(default)]
+    pub width: Option<u32>,
+    /// Height of the image in pixels.
+    #[serde(default)]
+    pub height: Option<u32>,
+}
+
+impl ChannelImage {
+    /// Creates an image from its URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        ChannelImage {
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Channel-level podcast metadata, written as `itunes:*` tags of RSS feeds.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+#[non_exhaustive]
+pub struct PodcastConf {
+    /// Author of the podcast; defaults to `RssConf::default_author`.
+    pub author: Option<String>,
+    /// Apple Podcasts categories of the podcast.
+    pub categories: Vec<PodcastCategory>,
+    /// Whether the podcast contains explicit content.
+    pub explicit: bool,
+    /// URL of the podcast artwork; defaults to the URL of `RssConf::image`.
+    pub image: Option<String>,
+    /// Name of the podcast owner.
+    pub owner_name: Option<String>,
+    /// Contact email of the podcast owner.
+    pub owner_email: Option<String>,
+}
+
+/// Apple Podcasts category, optionally with a subcategory, e.g.
+/// `Technology` or `Society & Culture` / `Documentary`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[non_exhaustive]
+pub struct PodcastCategory {
+    /// Name of the category.
+    pub name: String,
+    /// Name of the subcategory.
+    #[serde(default)]
+    pub subcategory: Option<String>,
+}
+
+impl PodcastCategory {
+    /// Creates a category without a subcategory.
+    pub fn new(name: impl Into<String>) -> Self {
+        PodcastCategory {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Feed configuration.
+///
+/// Construct it with [`RssConf::builder`] or [`RssConf::default`], or load it
+/// from a configuration file with [`RssConf::load`]; new options may be added
+/// without breaking existing call sites.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+#[non_exhaustive]
+pub struct RssConf {
+    /// Title of the feed.
+    pub title: String,
+    /// URL of the website the feed belongs to.
+    pub link: String,
+    /// Description of the feed.
+    pub description: String,
+    /// Custom delimiter of YAML front matter blocks, besides `---`.
+    pub delimiter: String,
+    /// Format of the generated feed.
+    pub format: FeedFormat,
+    /// Permanent, unique identifier of the feed, used as the Atom `<id>`.
+    /// Falls back to `link` when not set.
+    pub feed_id: Option<String>,
+    /// URL the feed itself is published at, emitted as a `rel="self"` link
+    /// (or `feed_url` in JSON Feed).
+    pub self_link: Option<String>,
+    /// Whether items carry only a summary or the full rendered post.
+    pub content: ItemContent,
+    /// Author used for posts without an `author` front matter field.
+    pub default_author: Option<String>,
+    /// Base URL that item URLs are derived from when a post has no `url`
+    /// front matter field. Falls back to `link` when not set.
+    pub base_url: Option<String>,
+    /// Template for derived item URLs, relative to `base_url`, e.g.
+    /// `/{year}/{month}/{slug}/`. Supports `{year}`, `{month}`, `{day}`,
+    /// `{slug}` and `{path}`; defaults to `/{path}/`.
+    pub permalink: Option<String>,
+    /// How item guids are derived.
+    pub guid: GuidStrategy,
+    /// Timezone for `pub_date` values without a UTC offset, such as
+    /// `2023-09-14`. Also used for RSS `<pubDate>` and permalink dates.
+    pub timezone: Tz,
+    /// The current time; posts with a later `pub_date` are held back until
+    /// then. Defaults to the system clock when not set.
+    pub now: Option<DateTime<Utc>>,
+    /// Maximum number of items in the feed; the newest posts are kept.
+    pub max_items: Option<usize>,
+    /// Only posts published within this many days before `now` are included.
+    pub max_age_days: Option<u32>,
+    /// Language of the feed, e.g. `en-us`.
+    pub language: Option<String>,
+    /// Copyright notice of the feed content.
+    pub copyright: Option<String>,
+    /// Email address of the person responsible for the content, e.g.
+    /// `editor@example.com (Jane Doe)`.
+    pub managing_editor: Option<String>,
+    /// Email address of the person responsible for technical issues.
+    pub web_master: Option<String>,
+    /// Number of minutes the feed may be cached before it is refreshed.
+    pub ttl: Option<u32>,
+    /// Image displayed with the feed.
+    pub image: Option<ChannelImage>,
+    /// Categories of the feed.
+    pub categories: Vec<String>,
+    /// Program used to generate the feed.
+    pub generator: Option<String>,
+    /// URL of the documentation of the feed format.
+    pub docs: Option<String>,
+    /// Hours (0-23, GMT) in which aggregators may skip reading the feed.
+    /// Later hours fail feed generation with [`MdrssError::InvalidSkipHour`].
+    #[serde(deserialize_with = "deserialize_skip_hours")]
+    pub skip_hours: Vec<u8>,
+    /// Days on which aggregators may skip reading the feed.
+    pub skip_days: Vec<Weekday>,
+    /// Renames item tags after normalization, e.g. `rustlang = "Rust"`.
+    /// Keys are normalized like tag names (lowercase, single-spaced); values
+    /// are used as written.
+    pub tag_map: BTreeMap<String, String>,
+    /// Sub-feeds written by [`generate_sub_feeds`](crate::generate_sub_feeds);
+    /// defaults to all of them.
+    pub sub_feeds: Vec<SubFeedKind>,
+    /// Path of each sub-feed relative to the output directory. `{kind}` is
+    /// replaced by `tags`, `authors` or `sections` and `{name}` by the
+    /// slugified tag, author or directory name; defaults to
+    /// `{kind}/{name}/rss.xml`.
+    pub sub_feed_path: String,
+    /// Podcast metadata of the channel. When set, RSS feeds carry the
+    /// `itunes:*` channel tags expected by podcast directories.
+    pub podcast: Option<PodcastConf>,
+    /// XML namespaces declared on RSS feeds, mapping a prefix to its URI,
+    /// e.g. `acme = "https://example.com/ns/acme"`.
+    pub namespaces: BTreeMap<String, String>,
+    /// Custom front matter keys written as extension elements of RSS items,
+    /// mapping a key to a `prefix:element` name, e.g. `team = "acme:team"`.
+    /// The prefix must be declared in `namespaces`, unless it is `podcast`,
+    /// `media` or `dc`; otherwise generating the feed fails with
+    /// [`MdrssError::UndeclaredNamespace`].
+    pub extensions: BTreeMap<String, String>,
+    /// YAML author registry mapping handles to a `name` and optional
+    /// `email`, `url` and `avatar`, relative to the markdown directory. When
+    /// set, `author`, `authors`, `default_author` and the podcast author hold
+    /// handles, and posts naming an unknown handle fail with
+    /// [`MdrssError::UnknownAuthor`]; an unknown default or podcast author
+    /// fails the whole run.
+    pub authors_file: Option<PathBuf>,
+}
+
+impl Default for RssConf {
+    fn default() -> Self {
+        RssConf {
+            title: String::new(),
+            link: String::new(),
+            description: String::new(),
+            delimiter: String::from("---"),
+            format: FeedFormat::default(),
+            feed_id: None,
+            self_link: None,
+            content: ItemContent::default(),
+            default_author: None,
+            base_url: None,
+            permalink: None,
+            guid: GuidStrategy::default(),
+            timezone: Tz::UTC,
+            now: None,
+            max_items: None,
+            max_age_days: None,
+            language: None,
+            copyright: None,
+            managing_editor: None,
+            web_master: None,
+            ttl: None,
+            image: None,
+            categories: Vec::new(),
+            generator: None,
+            docs: None,
+            skip_hours: Vec::new(),
+            skip_days: Vec::new(),
+            tag_map: BTreeMap::new(),
+            sub_feeds: vec![
+                SubFeedKind::Tags,
+                SubFeedKind::Authors,
+                SubFeedKind::Sections,
+            ],
+            sub_feed_path: String::from("{kind}/{name}/rss.xml"),
+            podcast: None,
+            namespaces: BTreeMap::new(),
+            extensions: BTreeMap::new(),
+            authors_file: None,
+        }
+    }
+}
+
+impl RssConf {
+    /// Returns a builder starting from the default configuration.
+    pub fn builder() -> RssConfBuilder {
+        RssConfBuilder::default()
+    }
+
+    /// Loads the configuration for a markdown directory.
+    ///
+    /// Reads the first of `mdrss.toml`, `mdrss.yaml` and `mdrss.yml` found in
+    /// `markdown_dir`, falling back to the defaults if there is none, and then
+    /// applies `MDRSS_*` environment variable overrides, e.g. `MDRSS_TITLE` or
+    /// `MDRSS_MAX_ITEMS`.
+    pub fn load(markdown_dir: &Path) -> Result<RssConf> {
+        let mut rss_conf = match CONFIG_FILE_NAMES
+            .iter()
+            .map(|name| markdown_dir.join(name))
+            .find(|path| path.is_file())
+        {
+            Some(path) => RssConf::from_file(&path)?,
+            None => RssConf::default(),
+        };
+        rss_conf.apply_env(std::env::vars_os())?;
+        Ok(rss_conf)
+    }
+
+    /// Reads the configuration from a TOML file, or a YAML file if the
+    /// extension is `.yaml` or `.yml`. Options missing from the file keep
+    /// their defaults.
+    pub fn from_file(path: &Path) -> Result<RssConf> {
+        let content = fs::read_to_string(path).map_err(|source| MdrssError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        match path.extension().and_then(|s| s.to_str()) {
+            Some("yaml" | "yml") => {
+                serde_yaml::from_str(&content).map_err(|source| MdrssError::Yaml {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+            _ => toml::from_str(&content).map_err(|source| MdrssError::Toml {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    // Function to check that `skip_hours` only holds hours of the day, as
+    // the public field can be set past the deserializer's check
+    pub(crate) fn check_skip_hours(&self) -> Result<()> {
+        match self.skip_hours.iter().find(|&&hour| hour > MAX_SKIP_HOUR) {
+            Some(&hour) => Err(MdrssError::InvalidSkipHour { hour }),
+            None => Ok(()),
+        }
+    }
+
+    // Function to apply `MDRSS_<OPTION>` overrides from environment variables
+    fn apply_env<I, K, V>(&mut self, vars: I) -> Result<()>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<OsString>,
+        V: Into<OsString>,
+    {
+        for (name, value) in vars {
+            // Unrelated variables may hold anything, including non-UTF-8 names
+            let Ok(name) = name.into().into_string() else {
+                continue;
+            };
+            let Some(option) = name.strip_prefix(ENV_PREFIX) else {
+                continue;
+            };
+            let value = value
+                .into()
+                .into_string()
+                .map_err(|value| MdrssError::Env {
+                    name: name.clone(),
+                    value: value.to_string_lossy().into_owned(),
+                    source: de::Error::custom("not valid UTF-8"),
+                })?;
+            match option {
+                "TITLE" => self.title = value,
+                "LINK" => self.link = value,
+                "DESCRIPTION" => self.description = value,
+                "DELIMITER" => self.delimiter = value,
+                "FORMAT" => self.format = parse_env(&name, value)?,
+                "FEED_ID" => self.feed_id = Some(value),
+                "SELF_LINK" => self.self_link = Some(value),
+                "CONTENT" => self.content = parse_env(&name, value)?,
+                "DEFAULT_AUTHOR" => self.default_author = Some(value),
+                "BASE_URL" => self.base_url = Some(value),
+                "PERMALINK" => self.permalink = Some(value),
+                "GUID" => self.guid = parse_env(&name, value)?,
+                "TIMEZONE" => self.timezone = parse_env(&name, value)?,
+                "NOW" => self.now = Some(parse_env(&name, value)?),
+                "MAX_ITEMS" => self.max_items = Some(parse_env(&name, value)?),
+                "MAX_AGE_DAYS" => self.max_age_days = Some(parse_env(&name, value)?),
+                "LANGUAGE" => self.language = Some(value),
+                "COPYRIGHT" => self.copyright = Some(value),
+                "MANAGING_EDITOR" => self.managing_editor = Some(value),
+                "WEB_MASTER" => self.web_master = Some(value),
+                "TTL" => self.ttl = Some(parse_env(&name, value)?),
+                "IMAGE" => self.image = Some(parse_env(&name, value)?),
+                "CATEGORIES" => self.categories = parse_env(&name, value)?,
+                "GENERATOR" => self.generator = Some(value),
+                "DOCS" => self.docs = Some(value),
+                "SKIP_HOURS" => self.skip_hours = parse_env::<SkipHours>(&name, value)?.0,
+                "SKIP_DAYS" => self.skip_days = parse_env(&name, value)?,
+                "TAG_MAP" => self.tag_map = parse_env(&name, value)?,
+                "SUB_FEEDS" => self.sub_feeds = parse_env(&name, value)?,
+                "SUB_FEED_PATH" => self.sub_feed_path = value,
+                "PODCAST" => self.podcast = Some(parse_env(&name, value)?),
+                "NAMESPACES" => self.namespaces = parse_env(&name, value)?,
+                "EXTENSIONS" => self.extensions = parse_env(&name, value)?,
+                "AUTHORS_FILE" => self.authors_file = Some(PathBuf::from(value)),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    // Function to get the configured current time, or the system clock
+    pub(crate) fn now(&self) -> DateTime<Utc> {
+        self.now.unwrap_or_else(Utc::now)
+    }
+}
+
+// Function to parse a non-string environment variable value as a YAML scalar
+fn parse_env<T: DeserializeOwned>(name: &str, value: String) -> Result<T> {
+    serde_yaml::from_str(&value).map_err(|source| MdrssError::Env {
+        name: name.to_string(),
+        value,
+        source,
+    })
+}
+
+// `MDRSS_SKIP_HOURS` value, validated like the configuration file field
+#[derive(Deserialize)]
+#[serde(transparent)]
+struct SkipHours(#[serde(deserialize_with = "deserialize_skip_hours")] Vec<u8>);
+
+// Function to deserialize `skip_hours`, rejecting hours past 23
+fn deserialize_skip_hours<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    let skip_hours = Vec::<u8>::deserialize(deserializer)?;
+    match skip_hours.iter().find(|&&hour| hour > MAX_SKIP_HOUR) {
+        Some(hour) => Err(de::Error::custom(format_args!(
+            "invalid skip hour {hour}, expected 0-{MAX_SKIP_HOUR}"
+        ))),
+        None => Ok(skip_hours),
+    }
+}
+
+/// Builder for [`RssConf`].
+///
+/// ```
+/// use mdrss::{FeedFormat, RssConf};
+///
+/// let rss_conf = RssConf::builder()
+///     .title("My Blog")
+///     .link("https://example.com")
+///     .description("Posts about things")
+///     .format(FeedFormat::Atom)
+///     .build();
+/// assert_eq!(rss_conf.delimiter, "---");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RssConfBuilder {
+    conf: RssConf,
+}
+
+impl RssConfBuilder {
+    /// Sets [`RssConf::title`].
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.conf.title = title.into();
+        self
+    }
+
+    /// Sets [`RssConf::link`].
+    pub fn link(mut self, link: impl Into<String>) -> Self {
+        self.conf.link = link.into();
+        self
+    }
+
+    /// Sets [`RssConf::description`].
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.conf.description = description.into();
+        self
+    }
+
+    /// Sets [`RssConf::delimiter`].
+    pub fn delimiter(mut self, delimiter: impl Into<String>) -> Self {
+        self.conf.delimiter = delimiter.into();
+        self
+    }
+
+    /// Sets [`RssConf::format`].
+    pub fn format(mut self, format: FeedFormat) -> Self {
+        self.conf.format = format;
+        self
+    }
+
+    /// Sets [`RssConf::feed_id`].
+    pub fn feed_id(mut self, feed_id: impl Into<String>) -> Self {
+        self.conf.feed_id = Some(feed_id.into());
+        self
+    }
+
+    /// Sets [`RssConf::self_link`].
+    pub fn self_link(mut self, self_link: impl Into<String>) -> Self {
+        self.conf.self_link = Some(self_link.into());
+        self
+    }
+
+    /// Sets [`RssConf::content`].
+    pub fn content(mut self, content: ItemContent) -> Self {
+        self.conf.content = content;
+        self
+    }
+
+    /// Sets [`RssConf::default_author`].
+    pub fn default_author(mut self, default_author: impl Into<String>) -> Self {
+        self.conf.default_author = Some(default_author.into());
+        self
+    }
+
+    /// Sets [`RssConf::base_url`].
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.conf.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets [`RssConf::permalink`].
+    pub fn permalink(mut self, permalink: impl Into<String>) -> Self {
+        self.conf.permalink = Some(permalink.into());
+        self
+    }
+
+    /// Sets [`RssConf::guid`].
+    pub fn guid(mut self, guid: GuidStrategy) -> Self {
+        self.conf.guid = guid;
+        self
+    }
+
+    /// Sets [`RssConf::timezone`].
+    pub fn timezone(mut self, timezone: Tz) -> Self {
+        self.conf.timezone = timezone;
+        self
+    }
+
+    /// Sets [`RssConf::now`].
+    pub fn now(mut self, now: DateTime<Utc>) -> Self {
+        self.conf.now = Some(now);
+        self
+    }
+
+    /// Sets [`RssConf::max_items`].
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.conf.max_items = Some(max_items);
+        self
+    }
+
+    /// Sets [`RssConf::max_age_days`].
+    pub fn max_age_days(mut self, max_age_days: u32) -> Self {
+        self.conf.max_age_days = Some(max_age_days);
+        self
+    }
+
+    /// Sets [`RssConf::language`].
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.conf.language = Some(language.into());
+        self
+    }
+
+    /// Sets [`RssConf::copyright`].
+    pub fn copyright(mut self, copyright: impl Into<String>) -> Self {
+        self.conf.copyright = Some(copyright.into());
+        self
+    }
+
+    /// Sets [`RssConf::managing_editor`].
+    pub fn managing_editor(mut self, managing_editor: impl Into<String>) -> Self {
+        self.conf.managing_editor = Some(managing_editor.into());
+        self
+    }
+
+    /// Sets [`RssConf::web_master`].
+    pub fn web_master(mut self, web_master: impl Into<String>) -> Self {
+        self.conf.web_master = Some(web_master.into());
+        self
+    }
+
+    /// Sets [`RssConf::ttl`].
+    pub fn ttl(mut self, ttl: u32) -> Self {
+        self.conf.ttl = Some(ttl);
+        self
+    }
+
+    /// Sets [`RssConf::image`].
+    pub fn image(mut self, image: ChannelImage) -> Self {
+        self.conf.image = Some(image);
+        self
+    }
+
+    /// Sets [`RssConf::categories`].
+    pub fn categories<I, V>(mut self, categories: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        self.conf.categories = categories.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`RssConf::generator`].
+    pub fn generator(mut self, generator: impl Into<String>) -> Self {
+        self.conf.generator = Some(generator.into());
+        self
+    }
+
+    /// Sets [`RssConf::docs`].
+    pub fn docs(mut self, docs: impl Into<String>) -> Self {
+        self.conf.docs = Some(docs.into());
+        self
+    }
+
+    /// Sets [`RssConf::skip_hours`].
+    pub fn skip_hours<I, V>(mut self, skip_hours: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<u8>,
+    {
+        self.conf.skip_hours = skip_hours.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`RssConf::skip_days`].
+    pub fn skip_days<I, V>(mut self, skip_days: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<Weekday>,
+    {
+        self.conf.skip_days = skip_days.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`RssConf::tag_map`].
+    pub fn tag_map<I, K, V>(mut self, tag_map: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.conf.tag_map = tag_map
+            .into_iter()
+            .map(|(from, to)| (from.into(), to.into()))
+            .collect();
+        self
+    }
+
+    /// Sets [`RssConf::sub_feeds`].
+    pub fn sub_feeds<I, V>(mut self, sub_feeds: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<SubFeedKind>,
+    {
+        self.conf.sub_feeds = sub_feeds.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets [`RssConf::sub_feed_path`].
+    pub fn sub_feed_path(mut self, sub_feed_path: impl Into<String>) -> Self {
+        self.conf.sub_feed_path = sub_feed_path.into();
+        self
+    }
+
+    /// Sets [`RssConf::podcast`].
+    pub fn podcast(mut self, podcast: PodcastConf) -> Self {
+        self.conf.podcast = Some(podcast);
+        self
+    }
+
+    /// Sets [`RssConf::namespaces`].
+    pub fn namespaces<I, K, V>(mut self, namespaces: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.conf.namespaces = namespaces
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+        self
+    }
+
+    /// Sets [`RssConf::extensions`].
+    pub fn extensions<I, K, V>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.conf.extensions = extensions
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+        self
+    }
+
+    /// Sets [`RssConf::authors_file`].
+    pub fn authors_file(mut self, authors_file: impl Into<PathBuf>) -> Self {
+        self.conf.authors_file = Some(authors_file.into());
+        self
+    }
+
+    /// Returns the configuration.
+    pub fn build(self) -> RssConf {
+        self.conf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_file_toml() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("mdrss.toml");
+        let content = r#"
+title = "My Blog"
+link = "https://example.com"
+format = "atom"
+guid = "path_hash"
+timezone = "Europe/Warsaw"
+max_items = 20
+"#;
+        fs::write(&path, content).unwrap();
+
+        let rss_conf = RssConf::from_file(&path).unwrap();
+        assert_eq!(rss_conf.title, "My Blog");
+        assert_eq!(rss_conf.link, "https://example.com");
+        assert_eq!(rss_conf.format, FeedFormat::Atom);
+        assert_eq!(rss_conf.guid, GuidStrategy::PathHash);
+        assert_eq!(rss_conf.timezone, chrono_tz::Europe::Warsaw);
+        assert_eq!(rss_conf.max_items, Some(20));
+        assert_eq!(rss_conf.delimiter, "---");
+    }
+
+    #[test]
+    fn test_from_file_yaml_unknown_option() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("mdrss.yaml");
+        fs::write(&path, "title: My Blog\ntitel: Typo\n").unwrap();
+
+        let err = RssConf::from_file(&path).unwrap_err();
+        assert!(matches!(err, MdrssError::Yaml { .. }));
+    }
+
+    #[test]
+    fn test_apply_env() {
+        let mut rss_conf = RssConf::builder().title("My Blog").build();
+        let vars = [
+            ("MDRSS_TITLE", "Overridden"),
+            ("MDRSS_CONTENT", "full"),
+            ("MDRSS_MAX_AGE_DAYS", "30"),
+            ("HOME", "/root"),
+        ]
+        .map(|(name, value)| (name.to_string(), value.to_string()));
+        rss_conf.apply_env(vars).unwrap();
+        assert_eq!(rss_conf.title, "Overridden");
+        assert_eq!(rss_conf.content, ItemContent::Full);
+        assert_eq!(rss_conf.max_age_days, Some(30));
+
+        let vars = [("MDRSS_MAX_ITEMS".to_string(), "many".to_string())];
+        let err = rss_conf.apply_env(vars).unwrap_err();
+        assert!(matches!(err, MdrssError::Env { ref name, .. } if name == "MDRSS_MAX_ITEMS"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_apply_env_non_utf8() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let invalid = || OsString::from_vec(vec![b'a', 0xff]);
+        let mut rss_conf = RssConf::default();
+        let vars = [
+            (invalid(), OsString::from("ignored")),
+            (OsString::from("BAD"), invalid()),
+            (OsString::from("MDRSS_TITLE"), OsString::from("My Blog")),
+        ];
+        rss_conf.apply_env(vars).unwrap();
+        assert_eq!(rss_conf.title, "My Blog");
+
+        let vars = [(OsString::from("MDRSS_TITLE"), invalid())];
+        let err = rss_conf.apply_env(vars).unwrap_err();
+        assert!(matches!(err, MdrssError::Env { ref name, .. } if name == "MDRSS_TITLE"));
+    }
+
+    #[test]
+    fn test_skip_hours_range() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("mdrss.toml");
+        fs::write(&path, "skip_hours = [0, 23]\n").unwrap();
+        assert_eq!(RssConf::from_file(&path).unwrap().skip_hours, [0, 23]);
+
+        fs::write(&path, "skip_hours = [6, 24]\n").unwrap();
+        let err = RssConf::from_file(&path).unwrap_err();
+        assert!(matches!(err, MdrssError::Toml { .. }));
+
+        let mut rss_conf = RssConf::default();
+        let vars = [("MDRSS_SKIP_HOURS".to_string(), "[255]".to_string())];
+        let err = rss_conf.apply_env(vars).unwrap_err();
+        assert!(matches!(err, MdrssError::Env { ref name, .. } if name == "MDRSS_SKIP_HOURS"));
+    }
+
+    #[test]
+    fn test_check_skip_hours() {
+        let rss_conf = RssConf::builder().skip_hours([0, 23]).build();
+        assert!(rss_conf.check_skip_hours().is_ok());
+
+        let mut rss_conf = RssConf::builder().skip_hours([1, 24]).build();
+        let err = rss_conf.check_skip_hours().unwrap_err();
+        assert!(matches!(err, MdrssError::InvalidSkipHour { hour: 24 }));
+
+        rss_conf.skip_hours = vec![99];
+        assert!(rss_conf.check_skip_hours().is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+// Formats with an explicit UTC offset, besides RFC 3339 and RFC 2822
+const OFFSET_FORMATS: [&str; 2] = ["%Y-%m-%d %H:%M:%S%.f%:z", "%Y-%m-%d %H:%M:%S%.f %z"];
+
+// Formats without an offset, interpreted in the default timezone
+const NAIVE_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+];
+
+// Function to interpret a local date and time in the given timezone
+fn from_local(naive: NaiveDateTime, timezone: Tz) -> DateTime<Utc> {
+    timezone
+        .from_local_datetime(&naive)
+        .earliest()
+        // Times skipped by a DST transition are read as UTC
+        .unwrap_or_else(|| timezone.from_utc_datetime(&naive))
+        .with_timezone(&Utc)
+}
+
+// Function to parse the publication date as a `DateTime<Utc>`.
+//
+// Accepts RFC 3339 and RFC 2822 dates, `YYYY-MM-DD HH:MM[:SS]` with an
+// optional offset, and plain `YYYY-MM-DD` dates. Dates without an offset are
+// interpreted in `timezone`.
+pub(crate) fn parse_pub_date(
+    date_str: &str,
+    timezone: Tz,
+) -> Result<DateTime<Utc>, chrono::format::ParseError> {
+    let date_str = date_str.trim();
+
+    // Keep the RFC 3339 error: it describes the preferred format
+    let rfc3339_error = match DateTime::parse_from_rfc3339(date_str) {
+        Ok(date) => return Ok(date.with_timezone(&Utc)),
+        Err(err) => err,
+    };
+    if let Ok(date) = DateTime::parse_from_rfc2822(date_str) {
+        return Ok(date.with_timezone(&Utc));
+    }
+    for format in OFFSET_FORMATS {
+        if let Ok(date) = DateTime::parse_from_str(date_str, format) {
+            return Ok(date.with_timezone(&Utc));
+        }
+    }
+    for format in NAIVE_FORMATS {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(date_str, format) {
+            return Ok(from_local(naive, timezone));
+        }
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
+        return Ok(from_local(date.and_time(Default::default()), timezone));
+    }
+
+    Err(rfc3339_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pub_date() {
+        let date_str = "2023-09-14T12:34:56Z";
+        let parsed_date = parse_pub_date(date_str, Tz::UTC).unwrap();
+        let expected_date = Utc.with_ymd_and_hms(2023, 9, 14, 12, 34, 56).unwrap();
+        assert_eq!(parsed_date, expected_date);
+    }
+
+    #[test]
+    fn test_parse_pub_date_lenient() {
+        let expected_date = Utc.with_ymd_and_hms(2023, 9, 14, 12, 34, 56).unwrap();
+        for date_str in [
+            "2023-09-14T14:34:56+02:00",
+            "Thu, 14 Sep 2023 12:34:56 +0000",
+            "Thu, 14 Sep 2023 12:34:56 GMT",
+            "2023-09-14 14:34:56+02:00",
+            "2023-09-14 14:34:56 +0200",
+            "2023-09-14 12:34:56",
+            "2023-09-14T12:34:56",
+        ] {
+            assert_eq!(
+                parse_pub_date(date_str, Tz::UTC).unwrap(),
+                expected_date,
+                "{date_str}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_pub_date_default_timezone() {
+        let parsed_date = parse_pub_date("2023-09-14", chrono_tz::Europe::Warsaw).unwrap();
+        let expected_date = Utc.with_ymd_and_hms(2023, 9, 13, 22, 0, 0).unwrap();
+        assert_eq!(parsed_date, expected_date);
+
+        let parsed_date = parse_pub_date("2023-09-14 12:00", chrono_tz::Europe::Warsaw).unwrap();
+        let expected_date = Utc.with_ymd_and_hms(2023, 9, 14, 10, 0, 0).unwrap();
+        assert_eq!(parsed_date, expected_date);
+    }
+
+    #[test]
+    fn test_parse_pub_date_invalid() {
+        assert!(parse_pub_date("yesterday", Tz::UTC).is_err());
+        assert!(parse_pub_date("2023-13-01", Tz::UTC).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use serde::Deserialize;
+use std::{fs, path::Path};
+use url::Url;
+
+use crate::MdrssError;
+
+// MIME type of enclosures whose type is neither given nor guessable
+const DEFAULT_MIME_TYPE: &str = "application/octet-stream";
+
+// Enclosure as written in front matter
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawEnclosure {
+    url: String,
+    #[serde(rename = "type")]
+    mime_type: Option<String>,
+    length: Option<u64>,
+}
+
+// Struct to hold a resolved enclosure with an absolute URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Enclosure {
+    pub(crate) url: String,
+    pub(crate) mime_type: String,
+    pub(crate) length: u64,
+}
+
+// Function to check whether an enclosure URL names a file next to the
+// markdown file, rather than an absolute or root-relative URL
+fn is_local(url: &str) -> bool {
+    Url::parse(url).is_err() && !url.starts_with('/')
+}
+
+// Function to resolve a relative URL against the item link
+pub(crate) fn absolute_url(url: &str, link: &str) -> String {
+    Url::parse(link)
+        .and_then(|base| base.join(url))
+        .map_or_else(|_| url.to_string(), String::from)
+}
+
+// Function to guess the MIME type of a file from the extension in its URL
+pub(crate) fn guess_mime_type(url: &str, default: &str) -> String {
+    mime_guess::from_path(url)
+        .first_raw()
+        .unwrap_or(default)
+        .to_string()
+}
+
+// Function to resolve a front matter enclosure of the markdown file at
+// `path`. Local files are read from disk for their length and published
+// relative to `directory`, the URL of the directory holding the markdown
+// file, so that the URL and the length refer to the same file; other URLs are
+// resolved against the item link. The MIME type is guessed from the file
+// extension; explicit values take precedence.
+pub(crate) fn resolve(
+    raw: RawEnclosure,
+    path: &Path,
+    directory: &str,
+    link: &str,
+) -> Result<Enclosure, MdrssError> {
+    let is_local = is_local(&raw.url);
+    let detected_length = if is_local && raw.length.is_none() {
+        let file = path.parent().unwrap_or(Path::new("")).join(&raw.url);
+        let metadata =
+            fs::metadata(&file).map_err(|source| MdrssError::Io { path: file, source })?;
+        Some(metadata.len())
+    } else {
+        None
+    };
+    let mime_type = raw
+        .mime_type
+        .unwrap_or_else(|| guess_mime_type(&raw.url, DEFAULT_MIME_TYPE));
+
+    Ok(Enclosure {
+        url: absolute_url(&raw.url, if is_local { directory } else { link }),
+        mime_type,
+        length: raw.length.or(detected_length).unwrap_or(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(url: &str) -> RawEnclosure {
+        RawEnclosure {
+            url: String::from(url),
+            mime_type: None,
+            length: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_local_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::write(temp_dir.path().join("episode.mp3"), [0; 42]).unwrap();
+        let path = temp_dir.path().join("episode.md");
+
+        let enclosure = resolve(
+            raw("episode.mp3"),
+            &path,
+            "https://example.com/",
+            "https://example.com/episode/",
+        )
+        .unwrap();
+        assert_eq!(
+            enclosure,
+            Enclosure {
+                url: String::from("https://example.com/episode.mp3"),
+                mime_type: String::from("audio/mpeg"),
+                length: 42,
+            }
+        );
+    }
+
+    #[test]
+    fn test_resolve_missing_local_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("episode.md");
+
+        let err = resolve(
+            raw("missing.pdf"),
+            &path,
+            "https://example.com/",
+            "https://example.com/",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.path(),
+            Some(temp_dir.path().join("missing.pdf").as_path())
+        );
+    }
+
+    #[test]
+    fn test_resolve_remote_url() {
+        let enclosure = resolve(
+            RawEnclosure {
+                url: String::from("/media/report.pdf"),
+                mime_type: None,
+                length: Some(1024),
+            },
+            Path::new("posts/report.md"),
+            "https://example.com/posts/",
+            "https://example.com/posts/report/",
+        )
+        .unwrap();
+        assert_eq!(enclosure.url, "https://example.com/media/report.pdf");
+        assert_eq!(enclosure.mime_type, "application/pdf");
+        assert_eq!(enclosure.length, 1024);
+
+        let enclosure = resolve(
+            raw("https://cdn.example.com/episode"),
+            Path::new("episode.md"),
+            "https://example.com/",
+            "https://example.com/",
+        )
+        .unwrap();
+        assert_eq!(enclosure.url, "https://cdn.example.com/episode");
+        assert_eq!(enclosure.mime_type, DEFAULT_MIME_TYPE);
+        assert_eq!(enclosure.length, 0);
+    }
+}
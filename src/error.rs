@@ -0,0 +1,170 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+/// Errors that can occur while turning markdown files into a feed.
+///
+/// Every variant except [`MdrssError::Write`], [`MdrssError::Env`],
+/// [`MdrssError::UndeclaredNamespace`] and [`MdrssError::InvalidSkipHour`]
+/// carries the path of the offending file so that callers can point at the
+/// broken post.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MdrssError {
+    /// The file could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// The feed could not be written to the output writer.
+    Write { source: io::Error },
+    /// An `MDRSS_*` environment variable holds an invalid value.
+    Env {
+        name: String,
+        value: String,
+        source: serde_yaml::Error,
+    },
+    /// The front matter or configuration file is not valid YAML.
+    Yaml {
+        path: PathBuf,
+        source: serde_yaml::Error,
+    },
+    /// The front matter or configuration file is not valid TOML.
+    Toml {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    /// The front matter is not valid JSON.
+    Json {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    /// The `pub_date` value could not be parsed.
+    DateParse {
+        path: PathBuf,
+        value: String,
+        source: chrono::ParseError,
+    },
+    /// The file does not start with a front matter block.
+    MissingFrontMatter { path: PathBuf },
+    /// The front matter block is never closed by its delimiter.
+    UnclosedFrontMatter { path: PathBuf, delimiter: String },
+    /// A required front matter field is absent.
+    MissingField { path: PathBuf, field: &'static str },
+    /// An author handle is not listed in the author registry.
+    UnknownAuthor { path: PathBuf, handle: String },
+    /// A custom front matter key is mapped to an element whose prefix is not
+    /// declared in `RssConf::namespaces`.
+    UndeclaredNamespace { key: String, element: String },
+    /// `RssConf::skip_hours` holds an hour greater than 23.
+    InvalidSkipHour { hour: u8 },
+}
+
+impl MdrssError {
+    /// The path of the file the error relates to, if any.
+    pub fn path(&self) -> Option<&Path> {
+        let path = match self {
+            MdrssError::Write { .. }
+            | MdrssError::Env { .. }
+            | MdrssError::UndeclaredNamespace { .. }
+            | MdrssError::InvalidSkipHour { .. } => return None,
+            MdrssError::Io { path, .. }
+            | MdrssError::Yaml { path, .. }
+            | MdrssError::Toml { path, .. }
+            | MdrssError::Json { path, .. }
+            | MdrssError::DateParse { path, .. }
+            | MdrssError::MissingFrontMatter { path }
+            | MdrssError::UnclosedFrontMatter { path, .. }
+            | MdrssError::MissingField { path, .. }
+            | MdrssError::UnknownAuthor { path, .. } => path,
+        };
+        Some(path)
+    }
+}
+
+impl fmt::Display for MdrssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MdrssError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            MdrssError::Write { source } => write!(f, "failed to write feed: {}", source),
+            MdrssError::Env {
+                name,
+                value,
+                source,
+            } => write!(f, "invalid value {:?} for {}: {}", value, name, source),
+            MdrssError::Yaml { path, source } => {
+                write!(f, "{}: invalid YAML: {}", path.display(), source)
+            }
+            MdrssError::Toml { path, source } => {
+                write!(f, "{}: invalid TOML: {}", path.display(), source)
+            }
+            MdrssError::Json { path, source } => {
+                write!(f, "{}: invalid front matter: {}", path.display(), source)
+            }
+            MdrssError::DateParse {
+                path,
+                value,
+                source,
+            } => write!(
+                f,
+                "{}: invalid pub_date {:?}: {}",
+                path.display(),
+                value,
+                source
+            ),
+            MdrssError::MissingFrontMatter { path } => {
+                write!(f, "{}: no front matter found", path.display())
+            }
+            MdrssError::UnclosedFrontMatter { path, delimiter } => write!(
+                f,
+                "{}: front matter is missing its closing `{}` line",
+                path.display(),
+                delimiter
+            ),
+            MdrssError::MissingField { path, field } => {
+                write!(
+                    f,
+                    "{}: missing front matter field `{}`",
+                    path.display(),
+                    field
+                )
+            }
+            MdrssError::UnknownAuthor { path, handle } => {
+                write!(f, "{}: unknown author `{}`", path.display(), handle)
+            }
+            MdrssError::UndeclaredNamespace { key, element } => write!(
+                f,
+                "extension `{}` for front matter key `{}` uses an undeclared namespace prefix",
+                element, key
+            ),
+            MdrssError::InvalidSkipHour { hour } => {
+                write!(f, "invalid skip hour {}, expected 0-23", hour)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MdrssError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MdrssError::Io { source, .. } | MdrssError::Write { source } => Some(source),
+            MdrssError::Env { source, .. } | MdrssError::Yaml { source, .. } => Some(source),
+            MdrssError::Toml { source, .. } => Some(source),
+            MdrssError::Json { source, .. } => Some(source),
+            MdrssError::DateParse { source, .. } => Some(source),
+            MdrssError::MissingFrontMatter { .. }
+            | MdrssError::UnclosedFrontMatter { .. }
+            | MdrssError::MissingField { .. }
+            | MdrssError::UnknownAuthor { .. }
+            | MdrssError::UndeclaredNamespace { .. }
+            | MdrssError::InvalidSkipHour { .. } => None,
+        }
+    }
+}
+
+impl From<MdrssError> for io::Error {
+    fn from(err: MdrssError) -> Self {
+        match err {
+            MdrssError::Io { source, .. } | MdrssError::Write { source } => source,
+            other => io::Error::new(io::ErrorKind::InvalidData, other),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use rss::extension::{dublincore, Extension, ExtensionMap};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::{media, podcast, MdrssError, RssConf};
+
+// Prefixes that may be used without declaring them in `RssConf::namespaces`,
+// with the namespaces declared for them when items use them
+pub(crate) const BUILT_IN_NAMESPACES: [(&str, &str); 3] = [
+    (podcast::NAMESPACE_PREFIX, podcast::NAMESPACE),
+    (media::NAMESPACE_PREFIX, media::NAMESPACE),
+    ("dc", dublincore::NAMESPACE),
+];
+
+// Function to turn a scalar front matter value into element or attribute text
+fn scalar_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+// Function to build the extension elements of one front matter value: a
+// scalar becomes the element text, an object's scalar members become
+// attributes (with `value` as the element text) and a list repeats the
+// element for each entry
+fn elements(name: &str, value: &Value) -> Vec<Extension> {
+    match value {
+        Value::Array(values) => values
+            .iter()
+            .flat_map(|value| elements(name, value))
+            .collect(),
+        Value::Object(members) => {
+            let attrs = members
+                .iter()
+                .filter(|(key, _)| key.as_str() != "value")
+                .filter_map(|(key, value)| Some((key.clone(), scalar_text(value)?)))
+                .collect();
+            vec![Extension {
+                name: name.to_string(),
+                value: members.get("value").and_then(scalar_text),
+                attrs,
+                ..Default::default()
+            }]
+        }
+        value => scalar_text(value)
+            .map(|text| Extension {
+                name: name.to_string(),
+                value: Some(text),
+                ..Default::default()
+            })
+            .into_iter()
+            .collect(),
+    }
+}
+
+// Function to add extension elements to a map, keyed by prefix and local name
+pub(crate) fn insert(map: &mut ExtensionMap, elements: Vec<Extension>) {
+    for element in elements {
+        let (prefix, local_name) = element.name.split_once(':').unwrap_or(("", &element.name));
+        map.entry(prefix.to_string())
+            .or_default()
+            .entry(local_name.to_string())
+            .or_default()
+            .push(element);
+    }
+}
+
+// Function to check that every prefixed element in `RssConf::extensions` has a
+// declared or built-in namespace, as an unbound prefix makes the feed ill-formed
+pub(crate) fn check_namespaces(rss_conf: &RssConf) -> Result<(), MdrssError> {
+    for (key, name) in &rss_conf.extensions {
+        let Some((prefix, _)) = name.split_once(':') else {
+            continue;
+        };
+        let is_declared = rss_conf.namespaces.contains_key(prefix)
+            || BUILT_IN_NAMESPACES
+                .iter()
+                .any(|(built_in, _)| *built_in == prefix);
+        if !is_declared {
+            return Err(MdrssError::UndeclaredNamespace {
+                key: key.clone(),
+                element: name.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// Function to build the extension elements of the custom front matter keys
+// mapped by `RssConf::extensions`
+pub(crate) fn item_extensions(extra: &BTreeMap<String, Value>, rss_conf: &RssConf) -> ExtensionMap {
+    let mut map = ExtensionMap::new();
+    for (key, name) in &rss_conf.extensions {
+        if let Some(value) = extra.get(key) {
+            insert(&mut map, elements(name, value));
+        }
+    }
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_item_extensions() {
+        let extra = BTreeMap::from([
+            (String::from("team"), json!("Platform")),
+            (String::from("reviewers"), json!(["Alice", "Bob"])),
+            (
+                String::from("thumbnail"),
+                json!({"url": "https://example.com/thumb.jpg", "width": 640}),
+            ),
+            (String::from("unmapped"), json!("ignored")),
+        ]);
+        let rss_conf = RssConf::builder()
+            .extensions([
+                ("team", "acme:team"),
+                ("reviewers", "acme:reviewer"),
+                ("thumbnail", "media:thumbnail"),
+            ])
+            .build();
+
+        let map = item_extensions(&extra, &rss_conf);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["acme"]["team"][0].value(), Some("Platform"));
+        let reviewers = &map["acme"]["reviewer"];
+        assert_eq!(reviewers.len(), 2);
+        assert_eq!(reviewers[1].name(), "acme:reviewer");
+        assert_eq!(reviewers[1].value(), Some("Bob"));
+        let thumbnail = &map["media"]["thumbnail"][0];
+        assert_eq!(thumbnail.value(), None);
+        assert_eq!(thumbnail.attrs()["url"], "https://example.com/thumb.jpg");
+        assert_eq!(thumbnail.attrs()["width"], "640");
+    }
+
+    #[test]
+    fn test_check_namespaces() {
+        let rss_conf = RssConf::builder()
+            .namespaces([("acme", "https://acme.example.com/ns")])
+            .extensions([
+                ("team", "acme:team"),
+                ("thumbnail", "media:thumbnail"),
+                ("rights", "dc:rights"),
+                ("plain", "plain"),
+            ])
+            .build();
+        assert!(check_namespaces(&rss_conf).is_ok());
+
+        let rss_conf = RssConf::builder()
+            .extensions([("team", "other:team")])
+            .build();
+        let err = check_namespaces(&rss_conf).unwrap_err();
+        assert!(matches!(
+            err,
+            MdrssError::UndeclaredNamespace { key, element } if key == "team" && element == "other:team"
+        ));
+    }
+}
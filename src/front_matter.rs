@@ -0,0 +1,388 @@
+use serde::Deserialize;
+use std::{collections::BTreeMap, path::Path};
+
+use crate::{category::RawCategory, enclosure::RawEnclosure, podcast::RawPodcastItem, MdrssError};
+
+// Delimiters of the standard static-site front matter blocks
+const YAML_DELIMITER: &str = "---";
+const TOML_DELIMITER: &str = "+++";
+
+// Front matter as written in the file, before required fields are checked
+#[derive(Debug, Deserialize)]
+struct RawFrontMatter {
+    title: Option<String>,
+    pub_date: Option<String>,
+    author: Option<String>,
+    #[serde(default)]
+    authors: Vec<String>,
+    url: Option<String>,
+    base_url: Option<String>,
+    description: Option<String>,
+    slug: Option<String>,
+    guid: Option<String>,
+    enclosure: Option<RawEnclosure>,
+    cover_image: Option<String>,
+    podcast: Option<RawPodcastItem>,
+    #[serde(default)]
+    tags: Vec<RawCategory>,
+    #[serde(default)]
+    categories: Vec<RawCategory>,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    unlisted: bool,
+    // Keys not known to mdrss, kept for `RssConf::extensions`
+    #[serde(flatten)]
+    extra: BTreeMap<String, serde_json::Value>,
+}
+
+// Struct to hold the parsed front matter; optional fields fall back to
+// defaults derived from the configuration and the markdown body
+#[derive(Debug)]
+pub(crate) struct FrontMatter {
+    pub(crate) title: Option<String>,
+    pub(crate) pub_date: String,
+    pub(crate) author: Option<String>,
+    pub(crate) authors: Vec<String>,
+    pub(crate) url: Option<String>,
+    pub(crate) base_url: Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) slug: Option<String>,
+    pub(crate) guid: Option<String>,
+    pub(crate) enclosure: Option<RawEnclosure>,
+    pub(crate) cover_image: Option<String>,
+    pub(crate) podcast: Option<RawPodcastItem>,
+    // `tags` followed by `categories`, before normalization
+    pub(crate) categories: Vec<RawCategory>,
+    pub(crate) draft: bool,
+    pub(crate) unlisted: bool,
+    pub(crate) extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl RawFrontMatter {
+    // Function to check that all required fields are present
+    fn validate(self, path: &Path) -> Result<FrontMatter, MdrssError> {
+        let pub_date = self.pub_date.ok_or_else(|| MdrssError::MissingField {
+            path: path.to_path_buf(),
+            field: "pub_date",
+        })?;
+
+        Ok(FrontMatter {
+            title: self.title,
+            pub_date,
+            author: self.author,
+            authors: self.authors,
+            url: self.url,
+            base_url: self.base_url,
+            description: self.description,
+            slug: self.slug,
+            guid: self.guid,
+            enclosure: self.enclosure,
+            cover_image: self.cover_image,
+            podcast: self.podcast,
+            categories: self.tags.into_iter().chain(self.categories).collect(),
+            draft: self.draft,
+            unlisted: self.unlisted,
+            extra: self.extra,
+        })
+    }
+}
+
+// Syntax of a delimited front matter block; JSON front matter is not
+// delimited and is handled by `split_json`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FrontMatterFormat {
+    // YAML between the configured delimiter or `---` lines
+    Yaml,
+    // TOML between `+++` lines (Hugo, Zola)
+    Toml,
+}
+
+impl FrontMatterFormat {
+    // Function to deserialize a delimited YAML or TOML block
+    fn parse(self, path: &Path, block: &str) -> Result<RawFrontMatter, MdrssError> {
+        match self {
+            FrontMatterFormat::Yaml => {
+                serde_yaml::from_str(block).map_err(|source| MdrssError::Yaml {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+            FrontMatterFormat::Toml => parse_toml(block).map_err(|source| MdrssError::Toml {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+}
+
+// Function to deserialize TOML front matter, reading native datetimes as strings
+fn parse_toml(block: &str) -> Result<RawFrontMatter, toml::de::Error> {
+    let table = block
+        .parse::<toml::Table>()?
+        .into_iter()
+        .map(|(key, value)| match value {
+            toml::Value::Datetime(datetime) => (key, toml::Value::String(datetime.to_string())),
+            value => (key, value),
+        })
+        .collect::<toml::Table>();
+    toml::Value::Table(table).try_into()
+}
+
+// Function to split a leading JSON object off the markdown body
+fn split_json<'a>(path: &Path, content: &'a str) -> Result<(RawFrontMatter, &'a str), MdrssError> {
+    let mut stream = serde_json::Deserializer::from_str(content).into_iter::<RawFrontMatter>();
+    match stream.next() {
+        Some(Ok(raw)) => Ok((raw, &content[stream.byte_offset()..])),
+        Some(Err(source)) => Err(MdrssError::Json {
+            path: path.to_path_buf(),
+            source,
+        }),
+        None => Err(MdrssError::MissingFrontMatter {
+            path: path.to_path_buf(),
+        }),
+    }
+}
+
+// Function to iterate over the lines of a file as `(start, line, end)`, where
+// `line` has its `\n` or `\r\n` terminator removed and `end` is the offset of
+// the next line
+fn lines(content: &str) -> impl Iterator<Item = (usize, &str, usize)> {
+    content.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        let line = line.strip_suffix('\n').unwrap_or(line);
+        Some((start, line.strip_suffix('\r').unwrap_or(line), *offset))
+    })
+}
+
+// Function to parse front matter from a markdown file, returning it along
+// with the markdown body that follows it.
+//
+// The block must open on the first non-blank line of the file (after an
+// optional byte order mark): a line holding only the configured delimiter or
+// `---` opens YAML, `+++` opens TOML and `{` opens a JSON object. Delimited
+// blocks end at the next line holding only the same delimiter.
+pub(crate) fn parse_front_matter<'a>(
+    path: &Path,
+    content: &'a str,
+    delimiter: &str,
+) -> Result<(FrontMatter, &'a str), MdrssError> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut lines = lines(content).skip_while(|(_, line, _)| line.trim().is_empty());
+
+    let (start, opener, block_start) =
+        lines.next().ok_or_else(|| MdrssError::MissingFrontMatter {
+            path: path.to_path_buf(),
+        })?;
+    let opener = opener.trim_end();
+    let (format, delimiter) = if opener == delimiter {
+        (FrontMatterFormat::Yaml, delimiter)
+    } else if opener == YAML_DELIMITER {
+        (FrontMatterFormat::Yaml, YAML_DELIMITER)
+    } else if opener == TOML_DELIMITER {
+        (FrontMatterFormat::Toml, TOML_DELIMITER)
+    } else if opener.starts_with('{') {
+        let (raw, body) = split_json(path, &content[start..])?;
+        return Ok((raw.validate(path)?, body));
+    } else {
+        return Err(MdrssError::MissingFrontMatter {
+            path: path.to_path_buf(),
+        });
+    };
+
+    let (block_end, body_start) = lines
+        .find(|(_, line, _)| line.trim_end() == delimiter)
+        .map(|(end, _, body_start)| (end, body_start))
+        .ok_or_else(|| MdrssError::UnclosedFrontMatter {
+            path: path.to_path_buf(),
+            delimiter: delimiter.to_string(),
+        })?;
+
+    let raw = format.parse(path, &content[block_start..block_end])?;
+    Ok((raw.validate(path)?, &content[body_start..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_front_matter() {
+        let content = r#"
+-rss-
+title: Test Title
+pub_date: 2023-09-14T12:34:56Z
+author: John Doe
+url: http://example.com
+description: A test description.
+-rss-
+"#;
+        let (front_matter, _) = parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap();
+        assert_eq!(front_matter.title.as_deref(), Some("Test Title"));
+        assert_eq!(front_matter.pub_date, "2023-09-14T12:34:56Z");
+        assert_eq!(front_matter.author.as_deref(), Some("John Doe"));
+        assert_eq!(front_matter.url.as_deref(), Some("http://example.com"));
+        assert_eq!(
+            front_matter.description.as_deref(),
+            Some("A test description.")
+        );
+    }
+
+    #[test]
+    fn test_parse_front_matter_tags() {
+        let content = r#"---
+pub_date: 2023-09-14
+tags: [Rust, Web]
+categories:
+  - name: Tutorials
+    domain: https://example.com/categories
+---
+"#;
+        let (front_matter, _) = parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap();
+        assert_eq!(
+            front_matter.categories,
+            [
+                RawCategory::Name(String::from("Rust")),
+                RawCategory::Name(String::from("Web")),
+                RawCategory::Full {
+                    name: String::from("Tutorials"),
+                    domain: Some(String::from("https://example.com/categories")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_front_matter_extra_keys() {
+        let content = r#"+++
+pub_date = 2023-09-14
+team = "Platform"
+episode_count = 3
++++
+"#;
+        let (front_matter, _) = parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap();
+        assert_eq!(front_matter.pub_date, "2023-09-14");
+        assert_eq!(front_matter.extra.len(), 2);
+        assert_eq!(front_matter.extra["team"], "Platform");
+        assert_eq!(front_matter.extra["episode_count"], 3);
+    }
+
+    #[test]
+    fn test_parse_front_matter_missing_field() {
+        let content = r#"
+-rss-
+title: Test Title
+author: John Doe
+url: http://example.com
+description: A test description.
+-rss-
+"#;
+        let err = parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap_err();
+        assert!(matches!(
+            err,
+            MdrssError::MissingField {
+                field: "pub_date",
+                ..
+            }
+        ));
+        assert_eq!(err.path(), Some(Path::new("test.md")));
+    }
+
+    #[test]
+    fn test_parse_front_matter_standard_yaml() {
+        let content = r#"---
+title: Test Title
+pub_date: 2023-09-14T12:34:56Z
+author: John Doe
+url: http://example.com
+description: A test description.
+---
+Body
+"#;
+        let (front_matter, body) =
+            parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap();
+        assert_eq!(front_matter.title.as_deref(), Some("Test Title"));
+        assert_eq!(body.trim(), "Body");
+    }
+
+    #[test]
+    fn test_parse_front_matter_toml() {
+        let content = r#"+++
+title = "Test Title"
+pub_date = 2023-09-14T12:34:56Z
+author = "John Doe"
+url = "http://example.com"
+description = "A test description."
++++
+Body
+"#;
+        let (front_matter, body) =
+            parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap();
+        assert_eq!(front_matter.title.as_deref(), Some("Test Title"));
+        assert_eq!(front_matter.pub_date, "2023-09-14T12:34:56Z");
+        assert_eq!(body.trim(), "Body");
+    }
+
+    #[test]
+    fn test_parse_front_matter_json() {
+        let content = r#"{
+    "title": "Test Title",
+    "pub_date": "2023-09-14T12:34:56Z",
+    "author": "John Doe",
+    "url": "http://example.com",
+    "description": "A test description."
+}
+Body
+"#;
+        let (front_matter, body) =
+            parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap();
+        assert_eq!(front_matter.author.as_deref(), Some("John Doe"));
+        assert_eq!(body.trim(), "Body");
+    }
+
+    #[test]
+    fn test_parse_front_matter_invalid_toml() {
+        let content = "+++\ntitle = \n+++\n";
+        let err = parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap_err();
+        assert!(matches!(err, MdrssError::Toml { .. }));
+    }
+
+    #[test]
+    fn test_parse_front_matter_anchored_to_start() {
+        let content = "Intro mentioning -rss- before the block.\n-rss-\ntitle: Test Title\n-rss-\n";
+        let err = parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap_err();
+        assert!(matches!(err, MdrssError::MissingFrontMatter { .. }));
+    }
+
+    #[test]
+    fn test_parse_front_matter_body_with_delimiters() {
+        let content = "---\ntitle: Test Title\npub_date: 2023-09-14T12:34:56Z\nauthor: John Doe\nurl: http://example.com\ndescription: A test description.\n---\nSee -rss- docs.\n\n---\n\nMore text.\n";
+        let (front_matter, body) =
+            parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap();
+        assert_eq!(front_matter.title.as_deref(), Some("Test Title"));
+        assert_eq!(body, "See -rss- docs.\n\n---\n\nMore text.\n");
+    }
+
+    #[test]
+    fn test_parse_front_matter_bom_and_crlf() {
+        let content = "\u{feff}\r\n-rss-\r\ntitle: Test Title\r\npub_date: 2023-09-14T12:34:56Z\r\nauthor: John Doe\r\nurl: http://example.com\r\ndescription: A test description.\r\n-rss-\r\nBody\r\n";
+        let (front_matter, body) =
+            parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap();
+        assert_eq!(
+            front_matter.description.as_deref(),
+            Some("A test description.")
+        );
+        assert_eq!(body, "Body\r\n");
+    }
+
+    #[test]
+    fn test_parse_front_matter_unclosed() {
+        let content = "+++\ntitle = \"Test Title\"\n";
+        let err = parse_front_matter(Path::new("test.md"), content, "-rss-").unwrap_err();
+        assert!(matches!(
+            err,
+            MdrssError::UnclosedFrontMatter { ref delimiter, .. } if delimiter == "+++"
+        ));
+    }
+}
@@ -0,0 +1,117 @@
+use serde::Serialize;
+
+use crate::{FeedItem, RssConf};
+
+const VERSION: &str = "https://jsonfeed.org/version/1.1";
+
+// Top-level JSON Feed object
+#[derive(Serialize)]
+pub(crate) struct JsonFeed {
+    version: &'static str,
+    title: String,
+    home_page_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    feed_url: Option<String>,
+    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    items: Vec<JsonFeedItem>,
+}
+
+// Single JSON Feed item
+#[derive(Serialize)]
+struct JsonFeedItem {
+    id: String,
+    url: String,
+    title: String,
+    content_html: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    date_published: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    authors: Vec<JsonFeedAuthor>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    attachments: Vec<JsonFeedAttachment>,
+}
+
+// JSON Feed author object
+#[derive(Serialize)]
+struct JsonFeedAuthor {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    avatar: Option<String>,
+}
+
+// JSON Feed attachment object
+#[derive(Serialize)]
+struct JsonFeedAttachment {
+    url: String,
+    mime_type: String,
+    size_in_bytes: u64,
+}
+
+// Function to turn a feed item into a JSON Feed item
+fn json_feed_item(item: FeedItem) -> JsonFeedItem {
+    // Full-content items carry the description as summary, otherwise it is
+    // the content; either way it may hold HTML
+    let (content_html, summary) = match item.content {
+        Some(content) => (content, item.description),
+        None => (item.description.unwrap_or_default(), None),
+    };
+
+    JsonFeedItem {
+        id: item.guid.value,
+        url: item.link,
+        title: item.title,
+        content_html,
+        summary,
+        date_published: item.pub_date.to_rfc3339(),
+        image: item.cover_image,
+        authors: item
+            .authors
+            .into_iter()
+            .map(|author| JsonFeedAuthor {
+                name: author.name,
+                url: author.url,
+                avatar: author.avatar,
+            })
+            .collect(),
+        tags: item
+            .categories
+            .into_iter()
+            .map(|category| category.name)
+            .collect(),
+        attachments: item
+            .enclosure
+            .map(|enclosure| {
+                vec![JsonFeedAttachment {
+                    url: enclosure.url,
+                    mime_type: enclosure.mime_type,
+                    size_in_bytes: enclosure.length,
+                }]
+            })
+            .unwrap_or_default(),
+    }
+}
+
+// Function to build the JSON Feed from sorted feed items
+pub(crate) fn build_feed(items: Vec<FeedItem>, rss_conf: &RssConf) -> JsonFeed {
+    JsonFeed {
+        version: VERSION,
+        title: rss_conf.title.clone(),
+        home_page_url: rss_conf.link.clone(),
+        feed_url: rss_conf.self_link.clone(),
+        description: rss_conf.description.clone(),
+        icon: rss_conf.image.as_ref().map(|image| image.url.clone()),
+        language: rss_conf.language.clone(),
+        items: items.into_iter().map(json_feed_item).collect(),
+    }
+}
@@ -1,85 +1,644 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Utc, Weekday};
+use rss::extension::atom::AtomExtensionBuilder;
+use rss::extension::dublincore::DublinCoreExtension;
 use rss::{ChannelBuilder, ItemBuilder};
-use serde::Deserialize;
 use std::fs::File;
 use std::{
-    fs, io,
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
     path::{Path, PathBuf},
 };
+use url::Url;
 use walkdir::WalkDir;
 
-// Struct to hold the parsed front matter
-#[derive(Debug, Deserialize)]
-struct FrontMatter {
+use author::{Author, AuthorRegistry};
+use category::Category;
+use date::parse_pub_date;
+use enclosure::Enclosure;
+use front_matter::parse_front_matter;
+use podcast::PodcastItem;
+use sub_feed::SubFeed;
+
+mod atom;
+mod author;
+mod category;
+mod conf;
+mod date;
+mod enclosure;
+mod error;
+mod extension;
+mod front_matter;
+mod json_feed;
+mod links;
+mod markdown;
+mod media;
+mod permalink;
+mod podcast;
+mod sub_feed;
+
+pub use chrono_tz::Tz;
+pub use conf::{
+    ChannelImage, FeedFormat, GuidStrategy, ItemContent, PodcastCategory, PodcastConf, RssConf,
+    RssConfBuilder, SubFeedKind,
+};
+pub use error::MdrssError;
+
+/// Result type of the mdrss API.
+pub type Result<T, E = MdrssError> = std::result::Result<T, E>;
+
+// Unique identifier of a feed item
+#[derive(Clone)]
+struct Guid {
+    value: String,
+    is_permalink: bool,
+}
+
+// Function to derive a content-independent guid from the file's path
+// relative to the markdown directory
+fn path_hash_guid(relative_path: &Path) -> String {
+    let relative_path = relative_path
+        .iter()
+        .map(|segment| segment.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    let uuid = uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_URL, relative_path.as_bytes());
+    uuid.urn().to_string()
+}
+
+// Format-neutral feed entry, rendered by each output format
+#[derive(Clone)]
+struct FeedItem {
+    // Path of the markdown file relative to the markdown directory
+    relative_path: PathBuf,
     title: String,
-    pub_date: String,
-    author: String,
-    url: String,
-    description: String,
+    pub_date: DateTime<Utc>,
+    // `author` followed by `authors`, or the default author
+    authors: Vec<Author>,
+    link: String,
+    guid: Guid,
+    description: Option<String>,
+    // Rendered HTML of the markdown body, set for full-content feeds
+    content: Option<String>,
+    // Media file attached to the item
+    enclosure: Option<Enclosure>,
+    // Absolute URL of the post's cover image
+    cover_image: Option<String>,
+    // Episode metadata of podcast feeds
+    podcast: Option<PodcastItem>,
+    // Front matter keys not known to mdrss
+    extra: BTreeMap<String, serde_json::Value>,
+    // Normalized `tags` and `categories`
+    categories: Vec<Category>,
+    // Drafts and unlisted posts are kept out of every feed
+    hidden: bool,
+}
+
+// Function to process a markdown file and extract the feed item information
+fn process_markdown_file(
+    dir: &Path,
+    path: &Path,
+    rss_conf: &RssConf,
+) -> Result<FeedItem, MdrssError> {
+    let content = fs::read_to_string(path).map_err(|source| MdrssError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let (front_matter, body) = parse_front_matter(path, &content, &rss_conf.delimiter)?;
+    let pub_date = parse_pub_date(&front_matter.pub_date, rss_conf.timezone).map_err(|source| {
+        MdrssError::DateParse {
+            path: path.to_path_buf(),
+            value: front_matter.pub_date.clone(),
+            source,
+        }
+    })?;
+
+    let title = front_matter
+        .title
+        .or_else(|| markdown::first_heading(body))
+        .ok_or_else(|| MdrssError::MissingField {
+            path: path.to_path_buf(),
+            field: "title",
+        })?;
+    let relative_path = path.strip_prefix(dir).unwrap_or(path);
+    let base_url = rss_conf.base_url.as_deref().unwrap_or(&rss_conf.link);
+    let link = front_matter.url.unwrap_or_else(|| {
+        let vars = permalink::PermalinkVars {
+            relative_path,
+            slug: front_matter.slug.as_deref(),
+            pub_date: pub_date.with_timezone(&rss_conf.timezone).date_naive(),
+        };
+        let template = rss_conf
+            .permalink
+            .as_deref()
+            .unwrap_or(permalink::DEFAULT_TEMPLATE);
+        permalink::join(base_url, &permalink::expand(template, &vars))
+    });
+    // Files next to the markdown file are published next to it as well
+    let directory = permalink::join(base_url, &permalink::directory(relative_path));
+    let enclosure = front_matter
+        .enclosure
+        .map(|raw| enclosure::resolve(raw, path, &directory, &link))
+        .transpose()?;
+    let mut authors = Vec::new();
+    for author in front_matter.author.into_iter().chain(front_matter.authors) {
+        if !authors.contains(&author) {
+            authors.push(author);
+        }
+    }
+    if authors.is_empty() {
+        authors.extend(rss_conf.default_author.clone());
+    }
+    let authors = authors.into_iter().map(Author::from_name).collect();
+    let cover_image = front_matter
+        .cover_image
+        .map(|image| enclosure::absolute_url(&image, &directory));
+    let podcast = front_matter.podcast.map(|raw| raw.resolve(&directory));
+    // Relative links in the post resolve against its own URL, unless the
+    // post sets `base_url`; a relative URL is taken from the channel link
+    let base = front_matter.base_url.as_deref().unwrap_or(&link);
+    let base = Url::parse(base)
+        .or_else(|_| Url::parse(&rss_conf.link).and_then(|channel| channel.join(base)))
+        .ok();
+    let absolutize = |html: String| match &base {
+        Some(base) => links::absolutize(&html, base),
+        None => html,
+    };
+    let guid = match (front_matter.guid, rss_conf.guid) {
+        (Some(value), _) => Guid {
+            value,
+            is_permalink: false,
+        },
+        (None, GuidStrategy::Permalink) => Guid {
+            value: link.clone(),
+            is_permalink: true,
+        },
+        (None, GuidStrategy::PathHash) => Guid {
+            value: path_hash_guid(relative_path),
+            is_permalink: false,
+        },
+    };
+
+    Ok(FeedItem {
+        relative_path: relative_path.to_path_buf(),
+        title,
+        pub_date,
+        authors,
+        link,
+        guid,
+        description: front_matter
+            .description
+            .or_else(|| markdown::first_paragraph(body))
+            .map(absolutize),
+        content: match rss_conf.content {
+            ItemContent::Full if !body.trim().is_empty() => {
+                Some(absolutize(markdown::render_html(body)))
+            }
+            _ => None,
+        },
+        enclosure,
+        cover_image,
+        podcast,
+        categories: category::categories(front_matter.categories, &rss_conf.tag_map),
+        hidden: front_matter.draft || front_matter.unlisted,
+        extra: front_matter.extra,
+    })
+}
+
+// Channel-level authors, looked up in the author registry like item authors
+#[derive(Debug, Default)]
+struct ChannelAuthors {
+    // `RssConf::default_author`
+    default: Option<Author>,
+    // `PodcastConf::author`, falling back to the default author
+    podcast: Option<Author>,
+}
+
+// Function to look up the default and podcast authors in the registry;
+// unknown handles are reported against the registry file at `path`
+fn channel_authors(
+    registry: &AuthorRegistry,
+    path: &Path,
+    rss_conf: &RssConf,
+) -> Result<ChannelAuthors> {
+    let resolve = |handle: &String| -> Result<Author> {
+        let mut author = Author::from_name(handle.clone());
+        registry.resolve(std::slice::from_mut(&mut author), path)?;
+        Ok(author)
+    };
+    let default = rss_conf.default_author.as_ref().map(resolve).transpose()?;
+    let podcast = match rss_conf
+        .podcast
+        .as_ref()
+        .and_then(|podcast| podcast.author.as_ref())
+    {
+        Some(handle) => Some(resolve(handle)?),
+        None => default.clone(),
+    };
+    Ok(ChannelAuthors { default, podcast })
 }
 
-// Function to parse the publication date as a `DateTime<Utc>`
-fn parse_pub_date(date_str: &str) -> Result<DateTime<Utc>, chrono::format::ParseError> {
-    date_str.parse::<DateTime<Utc>>()
+// Items collected from a directory, along with the files that failed to parse
+struct Collected {
+    items: Vec<FeedItem>,
+    failures: Vec<MdrssError>,
+    authors: ChannelAuthors,
 }
 
-// Function to parse front matter from a markdown file
-fn parse_front_matter(content: &str, delimiter: &str) -> Option<FrontMatter> {
-    let parts: Vec<&str> = content.splitn(3, delimiter).collect();
-    if parts.len() == 3 {
-        serde_yaml::from_str(parts[1]).ok()
-    } else {
-        None
+// Function to traverse directories and process all markdown files, leaving
+// out hidden posts and posts scheduled after `RssConf::now`. Fails only if
+// the author registry cannot be loaded or lacks the default or podcast author,
+// or if the configuration is invalid: a custom extension uses an undeclared
+// namespace prefix or `skip_hours` holds an hour past 23.
+fn collect_markdown_files(dir: &Path, rss_conf: &RssConf) -> Result<Collected> {
+    extension::check_namespaces(rss_conf)?;
+    rss_conf.check_skip_hours()?;
+    let now = rss_conf.now();
+    let registry_path = rss_conf.authors_file.as_ref().map(|file| dir.join(file));
+    let registry = AuthorRegistry::load(registry_path.as_deref())?;
+    let mut collected = Collected {
+        items: Vec::new(),
+        failures: Vec::new(),
+        authors: channel_authors(&registry, registry_path.as_deref().unwrap_or(dir), rss_conf)?,
+    };
+
+    for entry in WalkDir::new(dir) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => {
+                collected.failures.push(MdrssError::Io {
+                    path: err.path().unwrap_or(dir).to_path_buf(),
+                    source: err.into(),
+                });
+                continue;
+            }
+        };
+
+        let path = entry.path();
+        if !path.is_file() || path.extension().and_then(|s| s.to_str()) != Some("md") {
+            continue;
+        }
+
+        let item = process_markdown_file(dir, path, rss_conf).and_then(|mut item| {
+            registry.resolve(&mut item.authors, path)?;
+            Ok(item)
+        });
+        match item {
+            Ok(item) if !item.hidden && item.pub_date <= now => collected.items.push(item),
+            Ok(_) => {}
+            Err(err) => collected.failures.push(err),
+        }
     }
+
+    Ok(collected)
 }
 
-// Struct to hold an RSS item along with its parsed publication date
-struct RssItem {
-    pub_date: DateTime<Utc>,
-    item: rss::Item,
-}
-
-// Function to process a markdown file and extract the RSS item information
-fn process_markdown_file(path: &Path, delimiter: &str) -> Option<RssItem> {
-    fs::read_to_string(path).ok().and_then(|content| {
-        parse_front_matter(&content, delimiter).and_then(|front_matter| {
-            parse_pub_date(&front_matter.pub_date).ok().map(|pub_date| {
-                let item = ItemBuilder::default()
-                    .title(Some(front_matter.title))
-                    .pub_date(Some(front_matter.pub_date))
-                    .author(Some(front_matter.author))
-                    .link(Some(front_matter.url))
-                    .description(Some(front_matter.description))
-                    .build();
-
-                RssItem { pub_date, item }
-            })
-        })
+// Function to sort items by publication date (descending), breaking ties by
+// path, and apply the configured date window and item limit
+fn select_items(mut items: Vec<FeedItem>, rss_conf: &RssConf) -> Vec<FeedItem> {
+    items.sort_by(|a, b| {
+        b.pub_date
+            .cmp(&a.pub_date)
+            .then_with(|| a.relative_path.cmp(&b.relative_path))
+    });
+
+    // A window reaching past the earliest representable date has no lower bound
+    let oldest = rss_conf.max_age_days.and_then(|days| {
+        chrono::Duration::try_days(i64::from(days))
+            .and_then(|max_age| rss_conf.now().checked_sub_signed(max_age))
+    });
+    if let Some(oldest) = oldest {
+        items.retain(|item| item.pub_date >= oldest);
+    }
+    if let Some(max_items) = rss_conf.max_items {
+        items.truncate(max_items);
+    }
+
+    items
+}
+
+// Function to turn a feed item into an RSS item
+fn rss_item(item: FeedItem, rss_conf: &RssConf) -> rss::Item {
+    let itunes_ext = item
+        .podcast
+        .as_ref()
+        .map(|podcast| podcast::itunes_item(podcast, &item.authors));
+    let author_names = item
+        .authors
+        .iter()
+        .map(|author| author.name.clone())
+        .collect::<Vec<_>>();
+    let dublin_core_ext =
+        (!item.authors.is_empty() || !item.categories.is_empty()).then(|| DublinCoreExtension {
+            creators: author_names,
+            subjects: item
+                .categories
+                .iter()
+                .map(|category| category.name.clone())
+                .collect(),
+            ..Default::default()
+        });
+    let mut extensions = extension::item_extensions(&item.extra, rss_conf);
+    if let Some(podcast) = &item.podcast {
+        extension::insert(&mut extensions, podcast::podcast_elements(podcast));
+    }
+    if let Some(cover_image) = &item.cover_image {
+        extension::insert(&mut extensions, media::cover_elements(cover_image));
+    }
+
+    ItemBuilder::default()
+        .title(Some(item.title))
+        .pub_date(Some(
+            item.pub_date.with_timezone(&rss_conf.timezone).to_rfc2822(),
+        ))
+        .author(item.authors.first().map(Author::rss))
+        .link(Some(item.link))
+        .guid(Some(rss::Guid {
+            value: item.guid.value,
+            permalink: item.guid.is_permalink,
+        }))
+        .description(item.description)
+        .content(item.content)
+        .enclosure(item.enclosure.map(|enclosure| rss::Enclosure {
+            url: enclosure.url,
+            length: enclosure.length.to_string(),
+            mime_type: enclosure.mime_type,
+        }))
+        .categories(
+            item.categories
+                .into_iter()
+                .map(|category| rss::Category {
+                    name: category.name,
+                    domain: category.domain,
+                })
+                .collect::<Vec<_>>(),
+        )
+        .itunes_ext(itunes_ext)
+        .dublin_core_ext(dublin_core_ext)
+        .extensions(extensions)
+        .build()
+}
+
+// Function to get the English name of a weekday, as used by `<skipDays>`
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+// Function to build the RSS channel from sorted feed items
+fn build_rss_channel(
+    items: Vec<FeedItem>,
+    authors: &ChannelAuthors,
+    rss_conf: &RssConf,
+) -> rss::Channel {
+    let last_build_date = items
+        .iter()
+        .map(|item| item.pub_date)
+        .max()
+        .map(|date| date.with_timezone(&rss_conf.timezone).to_rfc2822());
+    let image = rss_conf.image.as_ref().map(|image| rss::Image {
+        url: image.url.clone(),
+        title: image
+            .title
+            .clone()
+            .unwrap_or_else(|| rss_conf.title.clone()),
+        link: image.link.clone().unwrap_or_else(|| rss_conf.link.clone()),
+        width: image.width.map(|width| width.to_string()),
+        height: image.height.map(|height| height.to_string()),
+        description: None,
+    });
+
+    let mut channel =
+        ChannelBuilder::default()
+            .title(rss_conf.title.as_str())
+            .link(rss_conf.link.as_str())
+            .description(rss_conf.description.as_str())
+            .language(rss_conf.language.clone())
+            .copyright(rss_conf.copyright.clone())
+            .managing_editor(rss_conf.managing_editor.clone())
+            .webmaster(rss_conf.web_master.clone())
+            .ttl(rss_conf.ttl.map(|ttl| ttl.to_string()))
+            .image(image)
+            .categories(
+                rss_conf
+                    .categories
+                    .iter()
+                    .map(|name| rss::Category {
+                        name: name.clone(),
+                        domain: None,
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .generator(rss_conf.generator.clone())
+            .docs(rss_conf.docs.clone())
+            .skip_hours(
+                rss_conf
+                    .skip_hours
+                    .iter()
+                    .map(u8::to_string)
+                    .collect::<Vec<_>>(),
+            )
+            .skip_days(
+                rss_conf
+                    .skip_days
+                    .iter()
+                    .map(|day| weekday_name(*day).to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .last_build_date(last_build_date)
+            .itunes_ext(rss_conf.podcast.as_ref().map(|podcast| {
+                podcast::itunes_channel(podcast, authors.podcast.as_ref(), rss_conf)
+            }))
+            .items(
+                items
+                    .into_iter()
+                    .map(|item| rss_item(item, rss_conf))
+                    .collect::<Vec<_>>(),
+            )
+            .build();
+
+    if let Some(self_link) = &rss_conf.self_link {
+        let link = atom_syndication::LinkBuilder::default()
+            .href(self_link.as_str())
+            .rel("self")
+            .mime_type(Some(String::from("application/rss+xml")))
+            .build();
+        channel.set_atom_ext(AtomExtensionBuilder::default().link(link).build());
+    }
+    // The rss crate only declares the namespaces it knows about
+    channel.namespaces.extend(rss_conf.namespaces.clone());
+    for (prefix, namespace) in extension::BUILT_IN_NAMESPACES {
+        let is_used = channel
+            .items()
+            .iter()
+            .any(|item| item.extensions().contains_key(prefix));
+        if is_used {
+            channel
+                .namespaces
+                .insert(prefix.to_string(), namespace.to_string());
+        }
+    }
+
+    channel
+}
+
+// Function to render sorted feed items in the configured format
+fn write_feed<W: Write>(
+    items: Vec<FeedItem>,
+    authors: &ChannelAuthors,
+    rss_conf: &RssConf,
+    writer: W,
+) -> io::Result<()> {
+    match rss_conf.format {
+        FeedFormat::Rss => build_rss_channel(items, authors, rss_conf)
+            .pretty_write_to(writer, b' ', 2)
+            .map(drop)
+            .map_err(io::Error::other),
+        FeedFormat::Atom => atom::build_feed(items, authors.default.as_ref(), rss_conf)
+            .write_with_config(
+                writer,
+                atom_syndication::WriteConfig {
+                    write_document_declaration: true,
+                    indent_size: Some(2),
+                },
+            )
+            .map(drop)
+            .map_err(io::Error::other),
+        FeedFormat::Json => {
+            serde_json::to_writer_pretty(writer, &json_feed::build_feed(items, rss_conf))
+                .map_err(io::Error::other)
+        }
+    }
+}
+
+// Function to collect markdown files and select the feed items: sorted by
+// publication date (descending) and truncated to the configured window
+fn collect_feed_items(dir: &Path, rss_conf: &RssConf) -> Result<Collected> {
+    let Collected {
+        items,
+        failures,
+        authors,
+    } = collect_markdown_files(dir, rss_conf)?;
+    Ok(Collected {
+        items: select_items(items, rss_conf),
+        failures,
+        authors,
     })
 }
 
-// Function to traverse directories and process all markdown files
-fn collect_markdown_files(dir: &Path, delimiter: &str) -> Vec<RssItem> {
-    WalkDir::new(dir)
-        .into_iter()
-        .filter_map(|entry| entry.ok()) // Handle invalid directory entries
-        .filter(|entry| {
-            entry.path().is_file()
-                && entry.path().extension().and_then(|s| s.to_str()) == Some("md")
-        })
-        .filter_map(|entry| process_markdown_file(entry.path(), delimiter))
-        .collect::<Vec<_>>() // Collect all valid markdown files
+// Function to write collected feed items, reporting write errors as
+// `MdrssError::Write`
+fn write_collected<W: Write>(
+    collected: Collected,
+    rss_conf: &RssConf,
+    writer: W,
+) -> Result<RssReport> {
+    let Collected {
+        items,
+        failures,
+        authors,
+    } = collected;
+    let item_count = items.len();
+
+    write_feed(items, &authors, rss_conf, writer).map_err(|source| MdrssError::Write { source })?;
+
+    Ok(RssReport {
+        item_count,
+        failures,
+    })
+}
+
+/// Outcome of a feed generation run.
+#[derive(Debug)]
+pub struct RssReport {
+    /// Number of items written to the feed.
+    pub item_count: usize,
+    /// Markdown files that could not be turned into feed items.
+    pub failures: Vec<MdrssError>,
+}
+
+impl RssReport {
+    /// Returns `true` if every markdown file was processed successfully.
+    pub fn is_clean(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Outcome of a sub-feed generation run.
+#[derive(Debug)]
+pub struct SubFeedReport {
+    /// Paths of the written feeds, mapped to their number of items.
+    pub feeds: BTreeMap<PathBuf, usize>,
+    /// Markdown files that could not be turned into feed items.
+    pub failures: Vec<MdrssError>,
+}
+
+impl SubFeedReport {
+    /// Returns `true` if every markdown file was processed successfully.
+    pub fn is_clean(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+// Function to derive the configuration of a sub-feed from the main one; the
+// sub-feed is titled after its tag, author or section and identified by its URL
+fn sub_feed_conf(rss_conf: &RssConf, feed: &SubFeed, path: &str) -> RssConf {
+    let base_url = rss_conf.base_url.as_deref().unwrap_or(&rss_conf.link);
+    let self_link = permalink::join(base_url, path);
+    RssConf {
+        title: format!("{} - {}", rss_conf.title, feed.name),
+        feed_id: Some(self_link.clone()),
+        self_link: Some(self_link),
+        ..rss_conf.clone()
+    }
 }
 
-pub struct RssConf {
-    pub title: String,
-    pub link: String,
-    pub description: String,
-    pub delimiter: String,
+/// Builds an RSS channel from markdown files without writing it anywhere.
+///
+/// The channel is always RSS 2.0, regardless of [`RssConf::format`]. Markdown
+/// files that cannot be parsed are skipped, as in [`generate_rss`].
+///
+/// # Arguments
+///
+/// * `markdown_dir` - A path to the directory containing the markdown files.
+/// * `rss_conf` - RSS configuration structure
+///
+pub fn build_channel(markdown_dir: &Path, rss_conf: &RssConf) -> Result<rss::Channel> {
+    let Collected { items, authors, .. } = collect_feed_items(markdown_dir, rss_conf)?;
+    Ok(build_rss_channel(items, &authors, rss_conf))
+}
+
+/// Generates a feed from markdown files and writes it to `writer`, e.g. a
+/// buffer, a socket or stdout.
+///
+/// The feed is written in the format selected by [`RssConf::format`]. Per-file
+/// failures are collected in [`RssReport::failures`].
+///
+/// # Arguments
+///
+/// * `markdown_dir` - A path to the directory containing the markdown files.
+/// * `rss_conf` - RSS configuration structure
+/// * `writer` - The destination of the generated feed.
+///
+pub fn write_to<W: Write>(markdown_dir: &Path, rss_conf: &RssConf, writer: W) -> Result<RssReport> {
+    let collected = collect_feed_items(markdown_dir, rss_conf)?;
+    write_collected(collected, rss_conf, writer)
 }
 
 /// The main API function to generate an RSS feed from markdown files.
 ///
+/// The feed is written in the format selected by [`RssConf::format`].
+///
+/// Markdown files that cannot be parsed are skipped. Use
+/// [`generate_rss_with_report`] to find out which files were skipped and why.
+///
 /// # Arguments
 ///
 /// * `markdown_dir` - A path to the directory containing the markdown files.
@@ -91,54 +650,120 @@ pub fn generate_rss(
     rss_output_path: &str,
     rss_conf: &RssConf,
 ) -> io::Result<()> {
+    generate_rss_with_report(markdown_dir, rss_output_path, rss_conf)?;
+    Ok(())
+}
+
+/// Generates an RSS feed like [`generate_rss`] and reports the markdown files
+/// that were left out of the feed.
+///
+/// Per-file failures do not abort generation; they are collected in
+/// [`RssReport::failures`]. An error is returned only if the author registry
+/// cannot be loaded or the feed itself cannot be written; the output file is
+/// not touched unless the markdown files were collected successfully.
+///
+/// # Arguments
+///
+/// * `markdown_dir` - A path to the directory containing the markdown files.
+/// * `rss_output_path` - The destination path for the generated RSS feed (rss.xml).
+/// * `rss_conf` - RSS configuration structure
+///
+pub fn generate_rss_with_report(
+    markdown_dir: &str,
+    rss_output_path: &str,
+    rss_conf: &RssConf,
+) -> Result<RssReport> {
     // Convert strings to PathBuf
     let directory = PathBuf::from(markdown_dir);
     let output_path = PathBuf::from(rss_output_path);
 
-    // Collect markdown files and generate RSS items
-    let mut rss_items = collect_markdown_files(&directory, rss_conf.delimiter.as_str());
+    // Collect before creating the file so that a failed run leaves an
+    // existing feed untouched
+    let collected = collect_feed_items(&directory, rss_conf)?;
 
-    // Sort items by publication date (descending)
-    rss_items.sort_by(|a, b| b.pub_date.cmp(&a.pub_date));
+    // Write the feed to a file, reporting errors against the output path
+    let file = File::create(&output_path).map_err(|source| MdrssError::Io {
+        path: output_path.clone(),
+        source,
+    })?;
+    write_collected(collected, rss_conf, file).map_err(|err| match err {
+        MdrssError::Write { source } => MdrssError::Io {
+            path: output_path,
+            source,
+        },
+        err => err,
+    })
+}
 
-    // Build the RSS feed with sorted items
-    let channel = ChannelBuilder::default()
-        .title(rss_conf.title.as_str())
-        .link(rss_conf.link.as_str())
-        .description(rss_conf.description.as_str())
-        .items(
-            rss_items
-                .into_iter()
-                .map(|rss_item| rss_item.item)
-                .collect::<Vec<_>>(),
-        )
-        .build();
+/// Generates one feed per tag, per author and per top-level subdirectory of
+/// `markdown_dir`, as selected by [`RssConf::sub_feeds`].
+///
+/// The markdown files are read once and every feed is written below
+/// `output_dir` at the path given by [`RssConf::sub_feed_path`]. Each feed is
+/// sorted and limited like the main feed, titled `"<title> - <name>"` and uses
+/// its own URL as `self_link` and `feed_id`.
+///
+/// # Arguments
+///
+/// * `markdown_dir` - A path to the directory containing the markdown files.
+/// * `output_dir` - The directory the sub-feeds are written to.
+/// * `rss_conf` - RSS configuration structure
+///
+pub fn generate_sub_feeds(
+    markdown_dir: &str,
+    output_dir: &str,
+    rss_conf: &RssConf,
+) -> Result<SubFeedReport> {
+    let output_dir = PathBuf::from(output_dir);
+    let Collected {
+        items,
+        failures,
+        authors,
+    } = collect_markdown_files(Path::new(markdown_dir), rss_conf)?;
+    let mut feeds = BTreeMap::new();
 
-    // Write the RSS feed to an XML file with pretty formatting
-    let mut file = File::create(output_path)?;
-    channel
-        .pretty_write_to(&mut file, b' ', 2)
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    for feed in sub_feed::group(items, &rss_conf.sub_feeds) {
+        let relative_path = sub_feed::expand(&rss_conf.sub_feed_path, feed.kind, &feed.slug);
+        let feed_conf = sub_feed_conf(rss_conf, &feed, &relative_path);
+        let items = select_items(feed.items, &feed_conf);
+        let item_count = items.len();
 
-    Ok(())
+        let output_path = output_dir.join(&relative_path);
+        let io_error = |source| MdrssError::Io {
+            path: output_path.clone(),
+            source,
+        };
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        let file = File::create(&output_path).map_err(io_error)?;
+        write_feed(items, &authors, &feed_conf, file).map_err(io_error)?;
+        feeds.insert(output_path, item_count);
+    }
+
+    Ok(SubFeedReport { feeds, failures })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::TimeZone;
     use std::fs;
 
-    #[test]
-    fn test_parse_pub_date() {
-        let date_str = "2023-09-14T12:34:56Z";
-        let parsed_date = parse_pub_date(date_str).unwrap();
-        let expected_date = Utc.with_ymd_and_hms(2023, 9, 14, 12, 34, 56).unwrap();
-        assert_eq!(parsed_date, expected_date);
+    // Configuration shared by the tests, using the `-rss-` delimiter
+    fn test_conf() -> RssConf {
+        RssConf::builder()
+            .title("Title")
+            .link("https://example.com")
+            .description("Description")
+            .delimiter("-rss-")
+            .build()
     }
 
     #[test]
-    fn test_parse_front_matter() {
+    fn test_collect_markdown_files() {
+        // Create a temp directory with mock markdown files
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("test.md");
         let content = r#"
 -rss-
 title: Test Title
@@ -148,17 +773,59 @@ url: http://example.com
 description: A test description.
 -rss-
 "#;
-        let front_matter = parse_front_matter(content, "-rss-").unwrap();
-        assert_eq!(front_matter.title, "Test Title");
-        assert_eq!(front_matter.pub_date, "2023-09-14T12:34:56Z");
-        assert_eq!(front_matter.author, "John Doe");
-        assert_eq!(front_matter.url, "http://example.com");
-        assert_eq!(front_matter.description, "A test description.");
+        fs::write(&file_path, content).unwrap();
+
+        // Collect markdown files
+        let collected = collect_markdown_files(temp_dir.path(), &test_conf()).unwrap();
+        assert_eq!(collected.items.len(), 1);
+        assert_eq!(collected.items[0].title, "Test Title");
+        assert!(collected.failures.is_empty());
     }
 
     #[test]
-    fn test_collect_markdown_files() {
-        // Create a temp directory with mock markdown files
+    fn test_build_rss_channel_self_link() {
+        let rss_conf = RssConf {
+            self_link: Some(String::from("https://example.com/rss.xml")),
+            ..test_conf()
+        };
+
+        let channel = build_rss_channel(Vec::new(), &ChannelAuthors::default(), &rss_conf);
+        let links = channel.atom_ext().unwrap().links();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].href(), "https://example.com/rss.xml");
+        assert_eq!(links[0].rel(), "self");
+    }
+
+    #[test]
+    fn test_collect_markdown_files_reports_failures() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let content = r#"
+-rss-
+title: Test Title
+pub_date: yesterday
+author: John Doe
+url: http://example.com
+description: A test description.
+-rss-
+"#;
+        fs::write(temp_dir.path().join("bad_date.md"), content).unwrap();
+        fs::write(temp_dir.path().join("no_front_matter.md"), "# Hello").unwrap();
+
+        let collected = collect_markdown_files(temp_dir.path(), &test_conf()).unwrap();
+        assert!(collected.items.is_empty());
+        assert_eq!(collected.failures.len(), 2);
+        assert!(collected
+            .failures
+            .iter()
+            .any(|err| matches!(err, MdrssError::DateParse { value, .. } if value == "yesterday")));
+        assert!(collected
+            .failures
+            .iter()
+            .any(|err| matches!(err, MdrssError::MissingFrontMatter { .. })));
+    }
+
+    #[test]
+    fn test_process_markdown_file_full_content() {
         let temp_dir = tempfile::tempdir().unwrap();
         let file_path = temp_dir.path().join("test.md");
         let content = r#"
@@ -169,12 +836,362 @@ author: John Doe
 url: http://example.com
 description: A test description.
 -rss-
+
+Some *emphasis* here.
 "#;
         fs::write(&file_path, content).unwrap();
 
-        // Collect markdown files
-        let rss_items = collect_markdown_files(temp_dir.path(), "-rss-");
-        assert_eq!(rss_items.len(), 1);
-        assert_eq!(rss_items[0].item.title(), Some("Test Title"));
+        let summary = process_markdown_file(temp_dir.path(), &file_path, &test_conf()).unwrap();
+        assert_eq!(summary.content, None);
+
+        let rss_conf = RssConf {
+            content: ItemContent::Full,
+            ..test_conf()
+        };
+        let full = process_markdown_file(temp_dir.path(), &file_path, &rss_conf).unwrap();
+        assert_eq!(
+            full.content.as_deref(),
+            Some("<p>Some <em>emphasis</em> here.</p>\n")
+        );
+        assert_eq!(full.description.as_deref(), Some("A test description."));
+    }
+
+    #[test]
+    fn test_process_markdown_file_absolute_links() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::create_dir(temp_dir.path().join("posts")).unwrap();
+        let file_path = temp_dir.path().join("posts/hello.md");
+        let content = r#"
+-rss-
+title: Test Title
+pub_date: 2023-09-14T12:34:56Z
+description: See <a href="/about">about</a>.
+-rss-
+
+![A](../img/a.png) and [notes](notes.html).
+"#;
+        fs::write(&file_path, content).unwrap();
+
+        let rss_conf = RssConf {
+            content: ItemContent::Full,
+            ..test_conf()
+        };
+        let item = process_markdown_file(temp_dir.path(), &file_path, &rss_conf).unwrap();
+        assert_eq!(
+            item.description.as_deref(),
+            Some(r#"See <a href="https://example.com/about">about</a>."#)
+        );
+        assert_eq!(
+            item.content.as_deref(),
+            Some(concat!(
+                r#"<p><img src="https://example.com/posts/img/a.png" alt="A" /> and "#,
+                r#"<a href="https://example.com/posts/hello/notes.html">notes</a>.</p>"#,
+                "\n"
+            ))
+        );
+
+        let content = content.replace(
+            "-rss-\n\n",
+            "base_url: https://cdn.example.com/hello/\n-rss-\n\n",
+        );
+        fs::write(&file_path, content).unwrap();
+        let item = process_markdown_file(temp_dir.path(), &file_path, &rss_conf).unwrap();
+        assert!(item
+            .content
+            .unwrap()
+            .contains(r#"src="https://cdn.example.com/img/a.png""#));
+    }
+
+    #[test]
+    fn test_process_markdown_file_fallbacks() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let posts_dir = temp_dir.path().join("posts");
+        fs::create_dir_all(&posts_dir).unwrap();
+        let file_path = posts_dir.join("hello.md");
+        let content = r#"
+-rss-
+pub_date: 2023-09-14T12:34:56Z
+-rss-
+
+# Hello World
+
+First paragraph
+of the post.
+
+Second paragraph.
+"#;
+        fs::write(&file_path, content).unwrap();
+
+        let rss_conf = RssConf {
+            default_author: Some(String::from("Jane Doe")),
+            base_url: Some(String::from("https://example.com/blog/")),
+            ..test_conf()
+        };
+        let item = process_markdown_file(temp_dir.path(), &file_path, &rss_conf).unwrap();
+        assert_eq!(item.title, "Hello World");
+        assert_eq!(item.authors, [Author::from_name(String::from("Jane Doe"))]);
+        assert_eq!(
+            item.description.as_deref(),
+            Some("First paragraph of the post.")
+        );
+        assert_eq!(item.link, "https://example.com/blog/posts/hello/");
+    }
+
+    #[test]
+    fn test_process_markdown_file_permalink() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("hello-world.md");
+        let content = r#"
+-rss-
+title: Test Title
+pub_date: 2023-09-14T12:34:56Z
+slug: hello
+-rss-
+"#;
+        fs::write(&file_path, content).unwrap();
+
+        let rss_conf = RssConf {
+            permalink: Some(String::from("/{year}/{month}/{slug}/")),
+            ..test_conf()
+        };
+        let item = process_markdown_file(temp_dir.path(), &file_path, &rss_conf).unwrap();
+        assert_eq!(item.link, "https://example.com/2023/09/hello/");
+    }
+
+    #[test]
+    fn test_process_markdown_file_local_enclosure() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let bundle_dir = temp_dir.path().join("posts/bundle");
+        fs::create_dir_all(&bundle_dir).unwrap();
+        let content =
+            "-rss-\ntitle: Episode\npub_date: 2023-09-14\nenclosure:\n  url: episode.mp3\n-rss-\n";
+        fs::write(temp_dir.path().join("posts/episode.mp3"), [0; 3]).unwrap();
+        fs::write(temp_dir.path().join("posts/single.md"), content).unwrap();
+        fs::write(bundle_dir.join("episode.mp3"), [0; 5]).unwrap();
+        fs::write(bundle_dir.join("index.md"), content).unwrap();
+
+        let rss_conf = test_conf();
+        let enclosure = |path: &Path| {
+            process_markdown_file(temp_dir.path(), path, &rss_conf)
+                .unwrap()
+                .enclosure
+                .map(|enclosure| (enclosure.url, enclosure.length))
+        };
+        // The URL names the file whose length was read, not one below the post URL
+        assert_eq!(
+            enclosure(&temp_dir.path().join("posts/single.md")),
+            Some((String::from("https://example.com/posts/episode.mp3"), 3))
+        );
+        assert_eq!(
+            enclosure(&bundle_dir.join("index.md")),
+            Some((
+                String::from("https://example.com/posts/bundle/episode.mp3"),
+                5
+            ))
+        );
+    }
+
+    #[test]
+    fn test_process_markdown_file_guid() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let posts_dir = temp_dir.path().join("posts");
+        fs::create_dir_all(&posts_dir).unwrap();
+        let plain_path = posts_dir.join("plain.md");
+        let custom_path = posts_dir.join("custom.md");
+        fs::write(
+            &plain_path,
+            "-rss-\ntitle: Plain\npub_date: 2023-09-14T12:34:56Z\n-rss-\n",
+        )
+        .unwrap();
+        fs::write(
+            &custom_path,
+            "-rss-\ntitle: Custom\npub_date: 2023-09-14T12:34:56Z\nguid: tag:example.com,2023:custom\n-rss-\n",
+        )
+        .unwrap();
+
+        let item = process_markdown_file(temp_dir.path(), &plain_path, &test_conf()).unwrap();
+        assert_eq!(item.guid.value, "https://example.com/posts/plain/");
+        assert!(item.guid.is_permalink);
+
+        let rss_conf = RssConf {
+            guid: GuidStrategy::PathHash,
+            ..test_conf()
+        };
+        let item = process_markdown_file(temp_dir.path(), &plain_path, &rss_conf).unwrap();
+        assert_eq!(item.guid.value, path_hash_guid(Path::new("posts/plain.md")));
+        assert!(item.guid.value.starts_with("urn:uuid:"));
+        assert!(!item.guid.is_permalink);
+
+        let item = process_markdown_file(temp_dir.path(), &custom_path, &rss_conf).unwrap();
+        assert_eq!(item.guid.value, "tag:example.com,2023:custom");
+        assert!(!item.guid.is_permalink);
+    }
+
+    #[test]
+    fn test_rss_item_pub_date_rfc2822() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("test.md");
+        fs::write(
+            &file_path,
+            "-rss-\ntitle: Test Title\npub_date: 2023-09-14\n-rss-\n",
+        )
+        .unwrap();
+
+        let rss_conf = RssConf {
+            timezone: chrono_tz::Europe::Warsaw,
+            ..test_conf()
+        };
+        let item = process_markdown_file(temp_dir.path(), &file_path, &rss_conf).unwrap();
+        let item = rss_item(item, &rss_conf);
+        assert_eq!(item.pub_date(), Some("Thu, 14 Sep 2023 00:00:00 +0200"));
+    }
+
+    #[test]
+    fn test_collect_markdown_files_filters_hidden_and_scheduled() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let post = |name: &str, extra: &str, pub_date: &str| {
+            let content = format!("-rss-\ntitle: {name}\npub_date: {pub_date}\n{extra}-rss-\n");
+            fs::write(temp_dir.path().join(format!("{name}.md")), content).unwrap();
+        };
+        post("published", "", "2023-09-14");
+        post("draft", "draft: true\n", "2023-09-14");
+        post("unlisted", "unlisted: true\n", "2023-09-14");
+        post("scheduled", "", "2023-10-01");
+
+        let rss_conf = RssConf {
+            now: Some("2023-09-20T00:00:00Z".parse().unwrap()),
+            ..test_conf()
+        };
+        let collected = collect_markdown_files(temp_dir.path(), &rss_conf).unwrap();
+        assert!(collected.failures.is_empty());
+        let titles = collected
+            .items
+            .iter()
+            .map(|item| item.title.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(titles, ["published"]);
+
+        let rss_conf = RssConf {
+            now: Some("2023-10-01T00:00:00Z".parse().unwrap()),
+            ..test_conf()
+        };
+        let collected = collect_markdown_files(temp_dir.path(), &rss_conf).unwrap();
+        assert_eq!(collected.items.len(), 2);
+    }
+
+    #[test]
+    fn test_select_items() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        for (name, pub_date) in [
+            ("b", "2023-09-14"),
+            ("a", "2023-09-14"),
+            ("c", "2023-09-10"),
+            ("old", "2023-01-01"),
+        ] {
+            let content = format!("-rss-\ntitle: {name}\npub_date: {pub_date}\n-rss-\n");
+            fs::write(temp_dir.path().join(format!("{name}.md")), content).unwrap();
+        }
+        let titles =
+            |items: Vec<FeedItem>| items.into_iter().map(|item| item.title).collect::<Vec<_>>();
+
+        let rss_conf = RssConf {
+            now: Some("2023-09-20T00:00:00Z".parse().unwrap()),
+            ..test_conf()
+        };
+        let items = collect_markdown_files(temp_dir.path(), &rss_conf)
+            .unwrap()
+            .items;
+        assert_eq!(
+            titles(select_items(items, &rss_conf)),
+            ["a", "b", "c", "old"]
+        );
+
+        let rss_conf = RssConf {
+            max_age_days: Some(30),
+            ..rss_conf
+        };
+        let items = collect_markdown_files(temp_dir.path(), &rss_conf)
+            .unwrap()
+            .items;
+        assert_eq!(titles(select_items(items, &rss_conf)), ["a", "b", "c"]);
+
+        let huge_window = RssConf {
+            max_age_days: Some(200_000_000),
+            ..rss_conf.clone()
+        };
+        let items = collect_markdown_files(temp_dir.path(), &huge_window)
+            .unwrap()
+            .items;
+        assert_eq!(
+            titles(select_items(items, &huge_window)),
+            ["a", "b", "c", "old"]
+        );
+
+        let rss_conf = RssConf {
+            max_items: Some(2),
+            ..rss_conf
+        };
+        let items = collect_markdown_files(temp_dir.path(), &rss_conf)
+            .unwrap()
+            .items;
+        assert_eq!(titles(select_items(items, &rss_conf)), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_build_rss_channel_metadata() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        for (name, pub_date) in [("old", "2023-09-10"), ("new", "2023-09-14")] {
+            let content = format!("-rss-\ntitle: {name}\npub_date: {pub_date}\n-rss-\n");
+            fs::write(temp_dir.path().join(format!("{name}.md")), content).unwrap();
+        }
+
+        let rss_conf = RssConf::builder()
+            .language("en-us")
+            .copyright("Copyright 2023 Jane Doe")
+            .managing_editor("editor@example.com (Jane Doe)")
+            .web_master("webmaster@example.com (John Doe)")
+            .ttl(60)
+            .image(ChannelImage::new("https://example.com/logo.png"))
+            .categories(["Rust", "Programming"])
+            .generator("mdrss")
+            .docs("https://www.rssboard.org/rss-specification")
+            .skip_hours([0, 1])
+            .skip_days([Weekday::Sat, Weekday::Sun])
+            .build();
+        let rss_conf = RssConf {
+            delimiter: String::from("-rss-"),
+            ..rss_conf
+        };
+        let items = collect_markdown_files(temp_dir.path(), &rss_conf)
+            .unwrap()
+            .items;
+        let channel = build_rss_channel(items, &ChannelAuthors::default(), &rss_conf);
+
+        assert_eq!(channel.language(), Some("en-us"));
+        assert_eq!(channel.copyright(), Some("Copyright 2023 Jane Doe"));
+        assert_eq!(
+            channel.managing_editor(),
+            Some("editor@example.com (Jane Doe)")
+        );
+        assert_eq!(
+            channel.webmaster(),
+            Some("webmaster@example.com (John Doe)")
+        );
+        assert_eq!(channel.ttl(), Some("60"));
+        let image = channel.image().unwrap();
+        assert_eq!(image.url(), "https://example.com/logo.png");
+        assert_eq!(image.title(), rss_conf.title);
+        assert_eq!(channel.categories().len(), 2);
+        assert_eq!(channel.generator(), Some("mdrss"));
+        assert_eq!(
+            channel.docs(),
+            Some("https://www.rssboard.org/rss-specification")
+        );
+        assert_eq!(channel.skip_hours(), ["0", "1"]);
+        assert_eq!(channel.skip_days(), ["Saturday", "Sunday"]);
+        assert_eq!(
+            channel.last_build_date(),
+            Some("Thu, 14 Sep 2023 00:00:00 +0000")
+        );
     }
 }
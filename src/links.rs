@@ -0,0 +1,178 @@
+use url::Url;
+
+// Attributes holding URLs that are made absolute
+const URL_ATTRIBUTES: [&str; 2] = ["href", "src"];
+
+// Function to undo the entity escaping of an HTML attribute value
+fn unescape(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+// Function to escape a URL for use as a double- or single-quoted attribute value
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+// Function to resolve an attribute value against the base URL, leaving
+// absolute URLs and values that cannot be resolved untouched
+fn absolute(value: &str, base: &Url) -> Option<String> {
+    let url = unescape(value);
+    if url.is_empty() || Url::parse(&url).is_ok() {
+        return None;
+    }
+    base.join(&url).ok().map(|url| escape(url.as_str()))
+}
+
+// Function to find the end of the tag starting at the beginning of `html`,
+// skipping `>` inside quoted attribute values
+fn tag_end(html: &str) -> usize {
+    let mut quote = None;
+    for (i, c) in html.char_indices() {
+        match (quote, c) {
+            (None, '>') => return i + 1,
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), c) if c == open => quote = None,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+// Function to rewrite the URL attributes of a single start tag such as
+// `<a href="../about">`; end tags, comments and stray `<` are copied as is
+fn rewrite_tag(tag: &str, base: &Url) -> String {
+    let bytes = tag.as_bytes();
+    if !bytes.get(1).is_some_and(u8::is_ascii_alphabetic) {
+        return tag.to_string();
+    }
+    let is_name_end = |b: u8| b.is_ascii_whitespace() || matches!(b, b'>' | b'/' | b'=');
+    let skip_whitespace = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        i
+    };
+
+    let mut output = String::with_capacity(tag.len());
+    let mut copied = 0;
+    // Skip the element name
+    let mut i = 1;
+    while i < bytes.len() && !is_name_end(bytes[i]) {
+        i += 1;
+    }
+
+    loop {
+        while i < bytes.len() && (bytes[i].is_ascii_whitespace() || bytes[i] == b'/') {
+            i += 1;
+        }
+        if i >= bytes.len() || bytes[i] == b'>' {
+            break;
+        }
+        let name_start = i;
+        while i < bytes.len() && !is_name_end(bytes[i]) {
+            i += 1;
+        }
+        let name = &tag[name_start..i];
+        i = skip_whitespace(i);
+        if bytes.get(i) != Some(&b'=') {
+            continue;
+        }
+        i = skip_whitespace(i + 1);
+
+        let (value_start, value_end) = match bytes.get(i) {
+            Some(&quote @ (b'"' | b'\'')) => {
+                let start = i + 1;
+                let end = tag[start..]
+                    .find(char::from(quote))
+                    .map_or(tag.len(), |offset| start + offset);
+                i = (end + 1).min(tag.len());
+                (start, end)
+            }
+            _ => {
+                let start = i;
+                while i < bytes.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'>' {
+                    i += 1;
+                }
+                (start, i)
+            }
+        };
+
+        let is_url_attribute = URL_ATTRIBUTES
+            .iter()
+            .any(|attribute| name.eq_ignore_ascii_case(attribute));
+        if is_url_attribute {
+            if let Some(url) = absolute(&tag[value_start..value_end], base) {
+                output.push_str(&tag[copied..value_start]);
+                output.push_str(&url);
+                copied = value_end;
+            }
+        }
+    }
+
+    output.push_str(&tag[copied..]);
+    output
+}
+
+// Function to rewrite relative `href` and `src` attributes in an HTML
+// fragment to absolute URLs resolved against `base`
+pub(crate) fn absolutize(html: &str, base: &Url) -> String {
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = tag_end(rest);
+        output.push_str(&rewrite_tag(&rest[..end], base));
+        rest = &rest[end..];
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> Url {
+        Url::parse("https://example.com/posts/hello/").unwrap()
+    }
+
+    #[test]
+    fn test_absolutize() {
+        let html = r#"<p><a href="/about">About</a> <img src="../img/a.png" alt="A"/></p>"#;
+        assert_eq!(
+            absolutize(html, &base()),
+            r#"<p><a href="https://example.com/about">About</a> <img src="https://example.com/posts/img/a.png" alt="A"/></p>"#
+        );
+    }
+
+    #[test]
+    fn test_absolutize_keeps_absolute_and_other_attributes() {
+        let html = r#"<a title="x > y" href='https://other.example/' data-src="a.png">a < b</a>"#;
+        assert_eq!(absolutize(html, &base()), html);
+        assert_eq!(
+            absolutize("<a href=mailto:me@example.com>", &base()),
+            "<a href=mailto:me@example.com>"
+        );
+    }
+
+    #[test]
+    fn test_absolutize_unquoted_and_escaped() {
+        assert_eq!(
+            absolutize("<A HREF=img/a.png?x=1&amp;y=2>", &base()),
+            "<A HREF=https://example.com/posts/hello/img/a.png?x=1&amp;y=2>"
+        );
+        assert_eq!(
+            absolutize(r##"<a href="#notes">"##, &base()),
+            r##"<a href="https://example.com/posts/hello/#notes">"##
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use pulldown_cmark::{html, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+// Function to create a parser for CommonMark plus GFM extensions
+fn parser(markdown: &str) -> Parser<'_> {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+
+    Parser::new_ext(markdown, options)
+}
+
+// Function to render a markdown body to HTML
+pub(crate) fn render_html(markdown: &str) -> String {
+    let mut output = String::new();
+    html::push_html(&mut output, parser(markdown));
+    output
+}
+
+// Function to extract the plain text of the first block matching `start`,
+// which ends at the matching `end` tag
+fn first_block_text(
+    markdown: &str,
+    start: impl Fn(&Tag) -> bool,
+    end: impl Fn(&TagEnd) -> bool,
+) -> Option<String> {
+    let mut events =
+        parser(markdown).skip_while(|event| !matches!(event, Event::Start(tag) if start(tag)));
+    events.next()?;
+
+    let mut text = String::new();
+    for event in events {
+        match event {
+            Event::End(tag) if end(&tag) => break,
+            Event::Text(value) | Event::Code(value) => text.push_str(&value),
+            Event::SoftBreak | Event::HardBreak => text.push(' '),
+            _ => {}
+        }
+    }
+
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+// Function to extract the text of the first `#` heading
+pub(crate) fn first_heading(markdown: &str) -> Option<String> {
+    first_block_text(
+        markdown,
+        |tag| {
+            matches!(
+                tag,
+                Tag::Heading {
+                    level: HeadingLevel::H1,
+                    ..
+                }
+            )
+        },
+        |tag| matches!(tag, TagEnd::Heading(HeadingLevel::H1)),
+    )
+}
+
+// Function to extract the text of the first paragraph
+pub(crate) fn first_paragraph(markdown: &str) -> Option<String> {
+    first_block_text(
+        markdown,
+        |tag| matches!(tag, Tag::Paragraph),
+        |tag| matches!(tag, TagEnd::Paragraph),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_html_gfm() {
+        let markdown = "| a | b |\n|---|---|\n| 1 | 2 |\n\nText[^1] ~~old~~\n\n[^1]: Note\n";
+        let output = render_html(markdown);
+        assert!(output.contains("<table>"));
+        assert!(output.contains("<td>1</td>"));
+        assert!(output.contains("<del>old</del>"));
+        assert!(output.contains(r#"class="footnote-definition""#));
+    }
+
+    #[test]
+    fn test_first_heading_and_paragraph() {
+        let markdown = "Intro with `code`\nover two lines.\n\n## Sub\n\n# The *Title*\n\nSecond.\n";
+        assert_eq!(first_heading(markdown).as_deref(), Some("The Title"));
+        assert_eq!(
+            first_paragraph(markdown).as_deref(),
+            Some("Intro with code over two lines.")
+        );
+        assert_eq!(first_heading("No heading here."), None);
+    }
+}
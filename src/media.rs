@@ -0,0 +1,31 @@
+use rss::extension::Extension;
+use std::collections::BTreeMap;
+
+// Prefix and URI of the Media RSS namespace
+pub(crate) const NAMESPACE_PREFIX: &str = "media";
+pub(crate) const NAMESPACE: &str = "http://search.yahoo.com/mrss/";
+
+// Function to build the `media:content` and `media:thumbnail` elements of a
+// cover image
+pub(crate) fn cover_elements(url: &str) -> Vec<Extension> {
+    let mut content_attrs = BTreeMap::from([
+        (String::from("url"), url.to_string()),
+        (String::from("medium"), String::from("image")),
+    ]);
+    if let Some(mime_type) = mime_guess::from_path(url).first_raw() {
+        content_attrs.insert(String::from("type"), mime_type.to_string());
+    }
+
+    vec![
+        Extension {
+            name: format!("{NAMESPACE_PREFIX}:content"),
+            attrs: content_attrs,
+            ..Default::default()
+        },
+        Extension {
+            name: format!("{NAMESPACE_PREFIX}:thumbnail"),
+            attrs: BTreeMap::from([(String::from("url"), url.to_string())]),
+            ..Default::default()
+        },
+    ]
+}
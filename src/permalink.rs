@@ -0,0 +1,181 @@
+use chrono::{Datelike, NaiveDate};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use std::path::Path;
+
+// Permalink template used when `RssConf::permalink` is not set
+pub(crate) const DEFAULT_TEMPLATE: &str = "/{path}/";
+
+// Characters percent-encoded in file names and slugs; `/` is kept so that a
+// slug can span several path segments
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+// Function to percent-encode a file name or slug for use in a URL path
+fn encode(segment: &str) -> String {
+    utf8_percent_encode(segment, PATH_SEGMENT).to_string()
+}
+
+// File stems that stand for their parent directory
+const INDEX_STEMS: [&str; 2] = ["index", "_index"];
+
+// Values a permalink template is filled from
+pub(crate) struct PermalinkVars<'a> {
+    // Path of the markdown file relative to the markdown directory
+    pub(crate) relative_path: &'a Path,
+    // The `slug` front matter field, if any
+    pub(crate) slug: Option<&'a str>,
+    // Publication date in the configured timezone
+    pub(crate) pub_date: NaiveDate,
+}
+
+impl PermalinkVars<'_> {
+    // Function to get the relative path segments without extension, dropping
+    // a trailing index file
+    fn segments(&self) -> Vec<String> {
+        let mut segments = self
+            .relative_path
+            .with_extension("")
+            .iter()
+            .map(|segment| segment.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        if segments
+            .last()
+            .is_some_and(|last| INDEX_STEMS.contains(&last.as_str()))
+        {
+            segments.pop();
+        }
+        segments
+    }
+
+    // Function to get the slug, defaulting to the file (or bundle directory) name
+    fn slug(&self) -> String {
+        match self.slug {
+            Some(slug) => slug.to_string(),
+            None => self.segments().pop().unwrap_or_default(),
+        }
+    }
+}
+
+// Function to expand a permalink template such as `/{year}/{month}/{slug}/`.
+//
+// Supported placeholders are `{year}`, `{month}`, `{day}` (from `pub_date`),
+// `{slug}` and `{path}` (the relative path without extension). Slugs and
+// file names are percent-encoded.
+pub(crate) fn expand(template: &str, vars: &PermalinkVars) -> String {
+    let path = vars
+        .segments()
+        .iter()
+        .map(|segment| encode(segment))
+        .collect::<Vec<_>>()
+        .join("/");
+    let mut expanded = template
+        .replace("{year}", &format!("{:04}", vars.pub_date.year()))
+        .replace("{month}", &format!("{:02}", vars.pub_date.month()))
+        .replace("{day}", &format!("{:02}", vars.pub_date.day()))
+        .replace("{slug}", &encode(&vars.slug()))
+        .replace("{path}", &path);
+
+    // Empty placeholders leave doubled slashes behind
+    while expanded.contains("//") {
+        expanded = expanded.replace("//", "/");
+    }
+    expanded
+}
+
+// Function to get the percent-encoded URL path of the directory holding the
+// markdown file, e.g. `/posts/` for `posts/hello.md`
+pub(crate) fn directory(relative_path: &Path) -> String {
+    let mut directory = String::from("/");
+    for segment in relative_path.parent().into_iter().flatten() {
+        directory.push_str(&encode(&segment.to_string_lossy()));
+        directory.push('/');
+    }
+    directory
+}
+
+// Function to join a base URL and an expanded permalink
+pub(crate) fn join(base_url: &str, permalink: &str) -> String {
+    format!(
+        "{}/{}",
+        base_url.trim_end_matches('/'),
+        permalink.trim_start_matches('/')
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars<'a>(relative_path: &'a str, slug: Option<&'a str>) -> PermalinkVars<'a> {
+        PermalinkVars {
+            relative_path: Path::new(relative_path),
+            slug,
+            pub_date: NaiveDate::from_ymd_opt(2023, 9, 4).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_expand_default_template() {
+        let expand_default = |path| expand(DEFAULT_TEMPLATE, &vars(path, None));
+        assert_eq!(expand_default("posts/hello.md"), "/posts/hello/");
+        assert_eq!(expand_default("posts/index.md"), "/posts/");
+        assert_eq!(expand_default("index.md"), "/");
+    }
+
+    #[test]
+    fn test_expand_date_and_slug() {
+        let template = "/{year}/{month}/{day}/{slug}/";
+        assert_eq!(
+            expand(template, &vars("posts/hello.md", None)),
+            "/2023/09/04/hello/"
+        );
+        assert_eq!(
+            expand(template, &vars("posts/hello/index.md", None)),
+            "/2023/09/04/hello/"
+        );
+        assert_eq!(
+            expand(template, &vars("posts/hello.md", Some("custom"))),
+            "/2023/09/04/custom/"
+        );
+    }
+
+    #[test]
+    fn test_expand_encodes_segments() {
+        assert_eq!(
+            expand(DEFAULT_TEMPLATE, &vars("My Posts/Hello #1?.md", None)),
+            "/My%20Posts/Hello%20%231%3F/"
+        );
+        assert_eq!(
+            expand("/{slug}/", &vars("posts/hello.md", Some("café au lait"))),
+            "/caf%C3%A9%20au%20lait/"
+        );
+    }
+
+    #[test]
+    fn test_directory() {
+        assert_eq!(directory(Path::new("posts/hello.md")), "/posts/");
+        assert_eq!(
+            directory(Path::new("My Posts/hello/index.md")),
+            "/My%20Posts/hello/"
+        );
+        assert_eq!(directory(Path::new("hello.md")), "/");
+    }
+
+    #[test]
+    fn test_join() {
+        assert_eq!(
+            join("https://example.com/", "/posts/hello/"),
+            "https://example.com/posts/hello/"
+        );
+        assert_eq!(join("https://example.com", "/"), "https://example.com/");
+    }
+}
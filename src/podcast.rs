@@ -0,0 +1,245 @@
+use rss::extension::itunes::{
+    ITunesCategory, ITunesChannelExtension, ITunesItemExtension, ITunesOwner,
+};
+use rss::extension::Extension;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::{author::Author, enclosure, PodcastConf, RssConf};
+
+// Prefix and URI of the Podcasting 2.0 namespace
+pub(crate) const NAMESPACE_PREFIX: &str = "podcast";
+pub(crate) const NAMESPACE: &str = "https://podcastindex.org/namespace/1.0";
+
+// MIME type of Podcasting 2.0 JSON chapters
+const CHAPTERS_MIME_TYPE: &str = "application/json+chapters";
+
+// Episode duration, written either as seconds or as `HH:MM:SS`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+enum RawDuration {
+    Seconds(u64),
+    Text(String),
+}
+
+// Transcript or chapters file as written in front matter
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawPodcastFile {
+    url: String,
+    #[serde(rename = "type")]
+    mime_type: Option<String>,
+    language: Option<String>,
+}
+
+// Episode metadata as written in the `podcast` front matter block
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RawPodcastItem {
+    duration: Option<RawDuration>,
+    episode: Option<u32>,
+    season: Option<u32>,
+    explicit: Option<bool>,
+    image: Option<String>,
+    transcript: Option<RawPodcastFile>,
+    chapters: Option<RawPodcastFile>,
+}
+
+// Transcript or chapters file with an absolute URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PodcastFile {
+    url: String,
+    mime_type: String,
+    language: Option<String>,
+}
+
+// Struct to hold the resolved episode metadata of an item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PodcastItem {
+    duration: Option<String>,
+    episode: Option<u32>,
+    season: Option<u32>,
+    explicit: Option<bool>,
+    image: Option<String>,
+    transcript: Option<PodcastFile>,
+    chapters: Option<PodcastFile>,
+}
+
+impl RawPodcastFile {
+    // Function to resolve the file URL against the directory URL of the
+    // markdown file, deriving a missing type from the URL with
+    // `default_mime_type`
+    fn resolve(
+        self,
+        directory: &str,
+        default_mime_type: impl FnOnce(&str) -> String,
+    ) -> PodcastFile {
+        let mime_type = self
+            .mime_type
+            .unwrap_or_else(|| default_mime_type(&self.url));
+        PodcastFile {
+            url: enclosure::absolute_url(&self.url, directory),
+            mime_type,
+            language: self.language,
+        }
+    }
+}
+
+impl RawPodcastItem {
+    // Function to resolve relative URLs against the directory URL of the
+    // markdown file, where local enclosures are published too
+    pub(crate) fn resolve(self, directory: &str) -> PodcastItem {
+        PodcastItem {
+            duration: self.duration.map(|duration| match duration {
+                RawDuration::Seconds(seconds) => seconds.to_string(),
+                RawDuration::Text(text) => text,
+            }),
+            episode: self.episode,
+            season: self.season,
+            explicit: self.explicit,
+            image: self
+                .image
+                .map(|image| enclosure::absolute_url(&image, directory)),
+            transcript: self.transcript.map(|transcript| {
+                transcript.resolve(directory, |url| {
+                    enclosure::guess_mime_type(url, "text/plain")
+                })
+            }),
+            // Chapters files are JSON but have their own MIME type
+            chapters: self
+                .chapters
+                .map(|chapters| chapters.resolve(directory, |_| String::from(CHAPTERS_MIME_TYPE))),
+        }
+    }
+}
+
+// Function to build the `itunes:*` channel tags, with the podcast author
+// already looked up in the author registry
+pub(crate) fn itunes_channel(
+    podcast: &PodcastConf,
+    author: Option<&Author>,
+    rss_conf: &RssConf,
+) -> ITunesChannelExtension {
+    let owner =
+        (podcast.owner_name.is_some() || podcast.owner_email.is_some()).then(|| ITunesOwner {
+            name: podcast.owner_name.clone(),
+            email: podcast.owner_email.clone(),
+        });
+
+    ITunesChannelExtension {
+        author: author.map(|author| author.name.clone()),
+        categories: podcast
+            .categories
+            .iter()
+            .map(|category| ITunesCategory {
+                text: category.name.clone(),
+                subcategory: category.subcategory.as_ref().map(|text| {
+                    Box::new(ITunesCategory {
+                        text: text.clone(),
+                        subcategory: None,
+                    })
+                }),
+            })
+            .collect(),
+        image: podcast
+            .image
+            .clone()
+            .or_else(|| rss_conf.image.as_ref().map(|image| image.url.clone())),
+        explicit: Some(podcast.explicit.to_string()),
+        owner,
+        summary: Some(rss_conf.description.clone()),
+        ..Default::default()
+    }
+}
+
+// Function to build the `itunes:*` tags of an episode
+pub(crate) fn itunes_item(podcast: &PodcastItem, authors: &[Author]) -> ITunesItemExtension {
+    let names = authors
+        .iter()
+        .map(|author| author.name.as_str())
+        .collect::<Vec<_>>();
+    ITunesItemExtension {
+        author: (!names.is_empty()).then(|| names.join(", ")),
+        image: podcast.image.clone(),
+        duration: podcast.duration.clone(),
+        explicit: podcast.explicit.map(|explicit| explicit.to_string()),
+        episode: podcast.episode.map(|episode| episode.to_string()),
+        season: podcast.season.map(|season| season.to_string()),
+        ..Default::default()
+    }
+}
+
+// Function to build a `podcast:*` element referring to a file
+fn file_extension(name: &str, file: &PodcastFile) -> Extension {
+    let mut attrs = BTreeMap::from([
+        (String::from("url"), file.url.clone()),
+        (String::from("type"), file.mime_type.clone()),
+    ]);
+    if let Some(language) = &file.language {
+        attrs.insert(String::from("language"), language.clone());
+    }
+    Extension {
+        name: format!("{NAMESPACE_PREFIX}:{name}"),
+        attrs,
+        ..Default::default()
+    }
+}
+
+// Function to build the Podcasting 2.0 `podcast:transcript` and
+// `podcast:chapters` elements of an episode
+pub(crate) fn podcast_elements(podcast: &PodcastItem) -> Vec<Extension> {
+    [
+        ("transcript", &podcast.transcript),
+        ("chapters", &podcast.chapters),
+    ]
+    .into_iter()
+    .filter_map(|(name, file)| file.as_ref().map(|file| file_extension(name, file)))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_podcast_item() {
+        let raw: RawPodcastItem = serde_yaml::from_str(
+            r#"
+duration: 2530
+episode: 3
+explicit: false
+image: cover.jpg
+transcript:
+  url: transcript.vtt
+  language: en
+chapters:
+  url: /chapters/3.json
+"#,
+        )
+        .unwrap();
+        let podcast = raw.resolve("https://example.com/episodes/3/");
+
+        assert_eq!(podcast.duration.as_deref(), Some("2530"));
+        assert_eq!(
+            podcast.image.as_deref(),
+            Some("https://example.com/episodes/3/cover.jpg")
+        );
+        assert_eq!(
+            podcast.transcript,
+            Some(PodcastFile {
+                url: String::from("https://example.com/episodes/3/transcript.vtt"),
+                mime_type: String::from("text/vtt"),
+                language: Some(String::from("en")),
+            })
+        );
+
+        let elements = podcast_elements(&podcast);
+        let chapters = &elements[1];
+        assert_eq!(chapters.name(), "podcast:chapters");
+        assert_eq!(
+            chapters.attrs()["url"],
+            "https://example.com/chapters/3.json"
+        );
+        assert_eq!(chapters.attrs()["type"], CHAPTERS_MIME_TYPE);
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::BTreeMap;
+use std::path::Component;
+
+use crate::{category, FeedItem, SubFeedKind};
+
+// Items of one tag, author or section feed
+pub(crate) struct SubFeed {
+    pub(crate) kind: SubFeedKind,
+    // Tag, author or directory name as it appears in the feed title
+    pub(crate) name: String,
+    // Name used in the output path
+    pub(crate) slug: String,
+    pub(crate) items: Vec<FeedItem>,
+}
+
+// Function to get the directory name of a sub-feed kind
+pub(crate) fn kind_dir(kind: SubFeedKind) -> &'static str {
+    match kind {
+        SubFeedKind::Tags => "tags",
+        SubFeedKind::Authors => "authors",
+        SubFeedKind::Sections => "sections",
+    }
+}
+
+// Function to turn a name into a lowercase, dash-separated path segment.
+// `+` and `#` are spelled out so that `C`, `C++` and `C#` stay apart.
+pub(crate) fn slugify(name: &str) -> String {
+    name.replace('+', " plus ")
+        .replace('#', " sharp ")
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase()
+}
+
+// Function to get the names an item is filed under for a sub-feed kind, as
+// `(name, key)` pairs where the key is slugified into the output path
+fn names(item: &FeedItem, kind: SubFeedKind) -> Vec<(String, String)> {
+    match kind {
+        SubFeedKind::Tags => item
+            .categories
+            .iter()
+            .map(|category| (category.name.clone(), category.name.clone()))
+            .collect(),
+        // Authors from a registry are filed under their handle
+        SubFeedKind::Authors => item
+            .authors
+            .iter()
+            .map(|author| (author.name.clone(), author.handle.clone()))
+            .collect(),
+        SubFeedKind::Sections => {
+            // Only files inside a subdirectory belong to a section
+            let mut components = item.relative_path.components();
+            match (components.next(), components.next()) {
+                (Some(Component::Normal(dir)), Some(_)) => {
+                    let dir = dir.to_string_lossy().into_owned();
+                    vec![(dir.clone(), dir)]
+                }
+                _ => Vec::new(),
+            }
+        }
+    }
+}
+
+// Function to group items into sub-feeds, ordered by kind and slug. Each
+// item is added once per sub-feed. Different keys that slugify alike get
+// numbered slugs (`web-dev`, `web-dev-2`) in key order, rather than sharing
+// a feed.
+pub(crate) fn group(items: Vec<FeedItem>, kinds: &[SubFeedKind]) -> Vec<SubFeed> {
+    // Items by kind and key, named after the first item filed under the key;
+    // keys differing only in case or spacing are the same
+    let mut groups: BTreeMap<(SubFeedKind, String), (String, Vec<FeedItem>)> = BTreeMap::new();
+    for item in items {
+        for &kind in kinds {
+            for (name, key) in names(&item, kind) {
+                let (_, group) = groups
+                    .entry((kind, category::normalize(&key)))
+                    .or_insert_with(|| (name, Vec::new()));
+                let is_duplicate = group
+                    .last()
+                    .is_some_and(|last| last.relative_path == item.relative_path);
+                if !is_duplicate {
+                    group.push(item.clone());
+                }
+            }
+        }
+    }
+
+    let mut feeds: BTreeMap<(SubFeedKind, String), SubFeed> = BTreeMap::new();
+    for ((kind, key), (name, items)) in groups {
+        let base_slug = slugify(&key);
+        if base_slug.is_empty() {
+            continue;
+        }
+        let mut slug = base_slug.clone();
+        let mut number = 2;
+        while feeds.contains_key(&(kind, slug.clone())) {
+            slug = format!("{base_slug}-{number}");
+            number += 1;
+        }
+        feeds.insert(
+            (kind, slug.clone()),
+            SubFeed {
+                kind,
+                name,
+                slug,
+                items,
+            },
+        );
+    }
+    feeds.into_values().collect()
+}
+
+// Function to expand the sub-feed path template
+pub(crate) fn expand(template: &str, kind: SubFeedKind, slug: &str) -> String {
+    template
+        .replace("{kind}", kind_dir(kind))
+        .replace("{name}", slug)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::author::Author;
+    use crate::category::Category;
+    use crate::Guid;
+    use chrono::Utc;
+    use std::path::PathBuf;
+
+    fn item(relative_path: &str, author: Option<&str>, tags: &[&str]) -> FeedItem {
+        FeedItem {
+            relative_path: PathBuf::from(relative_path),
+            title: String::from(relative_path),
+            pub_date: Utc::now(),
+            authors: author
+                .into_iter()
+                .map(|name| Author::from_name(String::from(name)))
+                .collect(),
+            link: String::from("https://example.com"),
+            guid: Guid {
+                value: String::from(relative_path),
+                is_permalink: false,
+            },
+            description: None,
+            content: None,
+            enclosure: None,
+            cover_image: None,
+            podcast: None,
+            categories: tags
+                .iter()
+                .map(|name| Category {
+                    name: String::from(*name),
+                    domain: None,
+                })
+                .collect(),
+            hidden: false,
+            extra: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Alice Smith"), "alice-smith");
+        assert_eq!(slugify("C++ / Rust"), "c-plus-plus-rust");
+        assert_eq!(slugify("C#"), "c-sharp");
+        assert_eq!(slugify("  "), "");
+    }
+
+    #[test]
+    fn test_group() {
+        let items = vec![
+            item("posts/a.md", Some("Alice"), &["rust", "web"]),
+            item("posts/b.md", Some("Bob"), &["Rust", "rust"]),
+            item("about.md", None, &[]),
+        ];
+        let all = [
+            SubFeedKind::Tags,
+            SubFeedKind::Authors,
+            SubFeedKind::Sections,
+        ];
+        let feeds = group(items, &all)
+            .into_iter()
+            .map(|feed| {
+                (
+                    expand("{kind}/{name}.xml", feed.kind, &feed.slug),
+                    feed.items.len(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            feeds,
+            [
+                (String::from("tags/rust.xml"), 2),
+                (String::from("tags/web.xml"), 1),
+                (String::from("authors/alice.xml"), 1),
+                (String::from("authors/bob.xml"), 1),
+                (String::from("sections/posts.xml"), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_keeps_similar_tags_apart() {
+        let items = vec![
+            item("a.md", None, &["c"]),
+            item("b.md", None, &["c++"]),
+            item("c.md", None, &["c#", "c"]),
+            item("d.md", None, &["web dev"]),
+            item("e.md", None, &["web-dev"]),
+        ];
+        let feeds = group(items, &[SubFeedKind::Tags])
+            .into_iter()
+            .map(|feed| (feed.slug, feed.name, feed.items.len()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            feeds,
+            [
+                (String::from("c"), String::from("c"), 2),
+                (String::from("c-plus-plus"), String::from("c++"), 1),
+                (String::from("c-sharp"), String::from("c#"), 1),
+                (String::from("web-dev"), String::from("web dev"), 1),
+                (String::from("web-dev-2"), String::from("web-dev"), 1),
+            ]
+        );
+    }
+}
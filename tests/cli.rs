@@ -0,0 +1,92 @@
+#![cfg(feature = "cli")]
+
+use std::fs;
+use std::process::Command;
+use tempfile::tempdir;
+
+#[test]
+fn test_cli_generate_and_check() {
+    let temp_dir = tempdir().unwrap();
+    let markdown_dir = temp_dir.path().join("markdowns");
+    fs::create_dir_all(&markdown_dir).unwrap();
+
+    let content = r#"---
+title: "Test Title"
+pub_date: "2023-09-14T12:34:56Z"
+---
+"#;
+    fs::write(markdown_dir.join("test.md"), content).unwrap();
+
+    let rss_output_path = temp_dir.path().join("rss.xml");
+    let status = Command::new(env!("CARGO_BIN_EXE_mdrss"))
+        .arg("--input")
+        .arg(&markdown_dir)
+        .arg("--output")
+        .arg(&rss_output_path)
+        .args([
+            "--title",
+            "Custom RSS Title",
+            "--link",
+            "https://example.com",
+        ])
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let rss_content = fs::read_to_string(&rss_output_path).unwrap();
+    assert!(rss_content.contains("<title>Custom RSS Title</title>"));
+    assert!(rss_content.contains("<title>Test Title</title>"));
+
+    let status = Command::new(env!("CARGO_BIN_EXE_mdrss"))
+        .arg("--input")
+        .arg(&markdown_dir)
+        .arg("--check")
+        .status()
+        .unwrap();
+    assert_eq!(status.code(), Some(0));
+
+    fs::write(markdown_dir.join("broken.md"), "---\ntitle: Broken\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_mdrss"))
+        .arg("--input")
+        .arg(&markdown_dir)
+        .arg("--check")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("broken.md"));
+}
+
+#[test]
+fn test_cli_failures_keep_output() {
+    let temp_dir = tempdir().unwrap();
+    let rss_output_path = temp_dir.path().join("rss.xml");
+    fs::write(&rss_output_path, "previous feed").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_mdrss"))
+        .arg("--input")
+        .arg(temp_dir.path().join("missing"))
+        .arg("--output")
+        .arg(&rss_output_path)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("missing"));
+
+    fs::write(
+        temp_dir.path().join("mdrss.toml"),
+        "authors_file = \"missing.yaml\"\n",
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_mdrss"))
+        .arg("--input")
+        .arg(temp_dir.path())
+        .arg("--output")
+        .arg(&rss_output_path)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("missing.yaml"));
+    assert_eq!(
+        fs::read_to_string(&rss_output_path).unwrap(),
+        "previous feed"
+    );
+}
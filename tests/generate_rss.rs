@@ -1,4 +1,7 @@
-use mdrss::{generate_rss, RssConf};
+use mdrss::{
+    build_channel, generate_rss, generate_rss_with_report, generate_sub_feeds, write_to,
+    FeedFormat, ItemContent, MdrssError, PodcastCategory, PodcastConf, RssConf,
+};
 use std::fs;
 use tempfile::tempdir;
 
@@ -25,12 +28,12 @@ description: "A test description."
     // Path for the output RSS file
     let rss_output_path = temp_dir.path().join("rss.xml");
 
-    let rss_conf = RssConf {
-        title: String::from("Custom RSS Title"),
-        link: String::from("https://example.com"),
-        description: String::from("A test description."),
-        delimiter: String::from("-rss-"),
-    };
+    let rss_conf = RssConf::builder()
+        .title("Custom RSS Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .delimiter("-rss-")
+        .build();
 
     // Call the API function to generate the RSS
     generate_rss(
@@ -44,5 +47,655 @@ description: "A test description."
     let rss_content = fs::read_to_string(rss_output_path).unwrap();
     assert!(rss_content.contains("<title>Test Title</title>"));
     assert!(rss_content.contains("<link>http://example.com</link>"));
+    assert!(rss_content.contains("<guid>http://example.com</guid>"));
+    assert!(rss_content.contains("<pubDate>Thu, 14 Sep 2023 12:34:56 +0000</pubDate>"));
     assert!(rss_content.contains("<description><![CDATA[A test description.]]></description>"));
 }
+
+#[test]
+fn test_generate_rss_with_report() {
+    let temp_dir = tempdir().unwrap();
+    let markdown_dir = temp_dir.path().join("markdowns");
+    fs::create_dir_all(&markdown_dir).unwrap();
+
+    let good = r#"
+-rss-
+title: "Good Post"
+pub_date: "2023-09-14T12:34:56Z"
+author: "John Doe"
+url: "http://example.com/good"
+description: "A good post."
+-rss-
+"#;
+    let broken = r#"
+-rss-
+title: "Broken Post"
+pub_date: "2023-09-14T12:34:56Z"
+author: "John Doe"
+url: [unterminated
+-rss-
+"#;
+    fs::write(markdown_dir.join("good.md"), good).unwrap();
+    fs::write(markdown_dir.join("broken.md"), broken).unwrap();
+
+    let rss_output_path = temp_dir.path().join("rss.xml");
+
+    let rss_conf = RssConf::builder()
+        .title("Custom RSS Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .delimiter("-rss-")
+        .build();
+
+    let report = generate_rss_with_report(
+        markdown_dir.to_str().unwrap(),
+        rss_output_path.to_str().unwrap(),
+        &rss_conf,
+    )
+    .expect("Failed to generate RSS feed");
+
+    assert_eq!(report.item_count, 1);
+    assert!(!report.is_clean());
+    assert_eq!(report.failures.len(), 1);
+    assert!(matches!(report.failures[0], MdrssError::Yaml { .. }));
+    assert_eq!(
+        report.failures[0].path(),
+        Some(markdown_dir.join("broken.md").as_path())
+    );
+
+    let rss_content = fs::read_to_string(rss_output_path).unwrap();
+    assert!(rss_content.contains("<title>Good Post</title>"));
+    assert!(!rss_content.contains("Broken Post"));
+}
+
+#[test]
+fn test_generate_atom() {
+    let temp_dir = tempdir().unwrap();
+    let markdown_dir = temp_dir.path().join("markdowns");
+    fs::create_dir_all(&markdown_dir).unwrap();
+
+    let content = r#"
+-rss-
+title: "Test Title"
+pub_date: "2023-09-14T12:34:56Z"
+author: "John Doe"
+url: "http://example.com/test"
+description: "A test description."
+-rss-
+"#;
+    fs::write(markdown_dir.join("test.md"), content).unwrap();
+
+    let atom_output_path = temp_dir.path().join("atom.xml");
+
+    let rss_conf = RssConf::builder()
+        .title("Custom Atom Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .delimiter("-rss-")
+        .format(FeedFormat::Atom)
+        .feed_id("urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6")
+        .self_link("https://example.com/atom.xml")
+        .build();
+
+    generate_rss(
+        markdown_dir.to_str().unwrap(),
+        atom_output_path.to_str().unwrap(),
+        &rss_conf,
+    )
+    .expect("Failed to generate Atom feed");
+
+    let atom_content = fs::read_to_string(atom_output_path).unwrap();
+    assert!(atom_content.contains(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#));
+    assert!(atom_content.contains("<id>urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6</id>"));
+    assert!(atom_content.contains("<updated>2023-09-14T12:34:56+00:00</updated>"));
+    assert!(atom_content.contains(r#"<link href="https://example.com/atom.xml" rel="self""#));
+    assert!(atom_content.contains("<title>Test Title</title>"));
+    assert!(atom_content.contains("<id>http://example.com/test</id>"));
+    assert!(atom_content.contains("<name>John Doe</name>"));
+}
+
+#[test]
+fn test_generate_json_feed() {
+    let temp_dir = tempdir().unwrap();
+    let markdown_dir = temp_dir.path().join("markdowns");
+    fs::create_dir_all(&markdown_dir).unwrap();
+
+    let content = r#"
+-rss-
+title: "Test Title"
+pub_date: "2023-09-14T12:34:56Z"
+author: "John Doe"
+url: "http://example.com/test"
+description: "A test description."
+-rss-
+"#;
+    fs::write(markdown_dir.join("test.md"), content).unwrap();
+
+    let json_output_path = temp_dir.path().join("feed.json");
+
+    let rss_conf = RssConf::builder()
+        .title("Custom JSON Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .delimiter("-rss-")
+        .format(FeedFormat::Json)
+        .self_link("https://example.com/feed.json")
+        .build();
+
+    generate_rss(
+        markdown_dir.to_str().unwrap(),
+        json_output_path.to_str().unwrap(),
+        &rss_conf,
+    )
+    .expect("Failed to generate JSON feed");
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(json_output_path).unwrap()).unwrap();
+    assert_eq!(json["version"], "https://jsonfeed.org/version/1.1");
+    assert_eq!(json["title"], "Custom JSON Title");
+    assert_eq!(json["feed_url"], "https://example.com/feed.json");
+    assert_eq!(json["items"][0]["id"], "http://example.com/test");
+    assert_eq!(json["items"][0]["title"], "Test Title");
+    assert_eq!(json["items"][0]["content_html"], "A test description.");
+    assert!(json["items"][0].get("content_text").is_none());
+    assert_eq!(
+        json["items"][0]["date_published"],
+        "2023-09-14T12:34:56+00:00"
+    );
+    assert_eq!(json["items"][0]["authors"][0]["name"], "John Doe");
+}
+
+#[test]
+fn test_generate_rss_full_content() {
+    let temp_dir = tempdir().unwrap();
+    let markdown_dir = temp_dir.path().join("markdowns");
+    fs::create_dir_all(&markdown_dir).unwrap();
+
+    let content = r#"
+-rss-
+title: "Test Title"
+pub_date: "2023-09-14T12:34:56Z"
+author: "John Doe"
+url: "http://example.com"
+description: "A test description."
+-rss-
+
+# Heading
+
+| a | b |
+|---|---|
+| 1 | 2 |
+"#;
+    fs::write(markdown_dir.join("test.md"), content).unwrap();
+
+    let rss_output_path = temp_dir.path().join("rss.xml");
+
+    let rss_conf = RssConf::builder()
+        .title("Custom RSS Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .delimiter("-rss-")
+        .content(ItemContent::Full)
+        .build();
+
+    generate_rss(
+        markdown_dir.to_str().unwrap(),
+        rss_output_path.to_str().unwrap(),
+        &rss_conf,
+    )
+    .expect("Failed to generate RSS feed");
+
+    let rss_content = fs::read_to_string(rss_output_path).unwrap();
+    assert!(rss_content.contains(r#"xmlns:content="http://purl.org/rss/1.0/modules/content/""#));
+    assert!(rss_content.contains("<content:encoded><![CDATA[<h1>Heading</h1>"));
+    assert!(rss_content.contains("<td>1</td>"));
+    assert!(rss_content.contains("<description><![CDATA[A test description.]]></description>"));
+}
+
+#[test]
+fn test_build_channel_and_write_to() {
+    let temp_dir = tempdir().unwrap();
+    let content = r#"
+-rss-
+title: "Test Title"
+pub_date: "2023-09-14T12:34:56Z"
+author: "John Doe"
+url: "http://example.com/test"
+description: "A test description."
+-rss-
+"#;
+    fs::write(temp_dir.path().join("test.md"), content).unwrap();
+
+    let rss_conf = RssConf::builder()
+        .title("Custom RSS Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .delimiter("-rss-")
+        .build();
+
+    let channel = build_channel(temp_dir.path(), &rss_conf).unwrap();
+    assert_eq!(channel.title(), "Custom RSS Title");
+    assert_eq!(channel.items().len(), 1);
+    assert_eq!(channel.items()[0].title(), Some("Test Title"));
+
+    let mut output = Vec::new();
+    let report = write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    assert_eq!(report.item_count, 1);
+    assert!(report.is_clean());
+    let rss_content = String::from_utf8(output).unwrap();
+    assert!(rss_content.contains("<title>Test Title</title>"));
+    assert!(!temp_dir.path().join("rss.xml").exists());
+}
+
+#[test]
+fn test_build_channel_categories() {
+    let temp_dir = tempdir().unwrap();
+    let content = r#"
+-rss-
+title: "Test Title"
+pub_date: "2023-09-14T12:34:56Z"
+url: "http://example.com/test"
+tags: ["Rust  Lang", "WEB"]
+categories:
+  - name: Tutorials
+    domain: "https://example.com/categories"
+-rss-
+"#;
+    fs::write(temp_dir.path().join("test.md"), content).unwrap();
+
+    let rss_conf = RssConf::builder()
+        .title("Custom RSS Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .delimiter("-rss-")
+        .tag_map([("rust lang", "Rust")])
+        .build();
+
+    let channel = build_channel(temp_dir.path(), &rss_conf).unwrap();
+    let categories = channel.items()[0].categories();
+    let names = categories.iter().map(|c| c.name()).collect::<Vec<_>>();
+    assert_eq!(names, ["Rust", "web", "tutorials"]);
+    assert_eq!(
+        categories[2].domain(),
+        Some("https://example.com/categories")
+    );
+}
+
+#[test]
+fn test_generate_sub_feeds() {
+    let temp_dir = tempdir().unwrap();
+    let markdown_dir = temp_dir.path().join("markdowns");
+    fs::create_dir_all(markdown_dir.join("posts")).unwrap();
+
+    let first = r#"---
+title: "First Post"
+pub_date: "2023-09-14T12:34:56Z"
+author: "Alice Smith"
+tags: [Rust, Web]
+---
+"#;
+    let second = r#"---
+title: "Second Post"
+pub_date: "2023-09-15T12:34:56Z"
+author: "Bob"
+tags: [rust]
+---
+"#;
+    fs::write(markdown_dir.join("posts/first.md"), first).unwrap();
+    fs::write(markdown_dir.join("second.md"), second).unwrap();
+
+    let output_dir = temp_dir.path().join("public");
+    let rss_conf = RssConf::builder()
+        .title("Blog")
+        .link("https://example.com")
+        .description("A test description.")
+        .build();
+
+    let report = generate_sub_feeds(
+        markdown_dir.to_str().unwrap(),
+        output_dir.to_str().unwrap(),
+        &rss_conf,
+    )
+    .unwrap();
+    assert!(report.is_clean());
+    assert_eq!(report.feeds.len(), 5);
+    assert_eq!(report.feeds[&output_dir.join("tags/rust/rss.xml")], 2);
+    assert_eq!(report.feeds[&output_dir.join("sections/posts/rss.xml")], 1);
+
+    let rss_content = fs::read_to_string(output_dir.join("authors/alice-smith/rss.xml")).unwrap();
+    assert!(rss_content.contains("<title>Blog - Alice Smith</title>"));
+    assert!(rss_content.contains("<title>First Post</title>"));
+    assert!(!rss_content.contains("<title>Second Post</title>"));
+    assert!(rss_content.contains(r#"href="https://example.com/authors/alice-smith/rss.xml""#));
+}
+
+#[test]
+fn test_write_podcast_feed() {
+    let temp_dir = tempdir().unwrap();
+    let content = r#"---
+title: "Episode 3"
+pub_date: "2023-09-14T12:34:56Z"
+author: "Jane Doe"
+enclosure:
+  url: "https://cdn.example.com/episode-3.mp3"
+  length: 24986239
+podcast:
+  duration: "00:42:10"
+  episode: 3
+  season: 1
+  explicit: false
+  transcript:
+    url: transcript.vtt
+  chapters:
+    url: chapters.json
+---
+"#;
+    // A bundle: the transcript and chapters sit next to `index.md`
+    let episode_dir = temp_dir.path().join("episodes/3");
+    fs::create_dir_all(&episode_dir).unwrap();
+    fs::write(episode_dir.join("index.md"), content).unwrap();
+
+    let mut podcast = PodcastConf::default();
+    podcast.author = Some(String::from("Jane Doe"));
+    podcast.categories = vec![PodcastCategory::new("Technology")];
+    podcast.image = Some(String::from("https://example.com/artwork.jpg"));
+    podcast.owner_email = Some(String::from("jane@example.com"));
+    let rss_conf = RssConf::builder()
+        .title("Podcast")
+        .link("https://example.com")
+        .description("A test podcast.")
+        .podcast(podcast)
+        .build();
+
+    let mut output = Vec::new();
+    write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    let rss_content = String::from_utf8(output).unwrap();
+    assert!(rss_content.contains(r#"xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd""#));
+    assert!(rss_content.contains(r#"xmlns:podcast="https://podcastindex.org/namespace/1.0""#));
+    assert!(rss_content.contains(r#"<itunes:category text="Technology">"#));
+    assert!(rss_content.contains(r#"<itunes:image href="https://example.com/artwork.jpg"/>"#));
+    assert!(rss_content.contains("<itunes:explicit>false</itunes:explicit>"));
+    assert!(rss_content.contains("<itunes:email>jane@example.com</itunes:email>"));
+    assert!(rss_content.contains(r#"<enclosure url="https://cdn.example.com/episode-3.mp3" length="24986239" type="audio/mpeg"/>"#));
+    assert!(rss_content.contains("<itunes:duration>00:42:10</itunes:duration>"));
+    assert!(rss_content.contains("<itunes:episode>3</itunes:episode>"));
+    assert!(rss_content.contains("<itunes:season>1</itunes:season>"));
+    assert!(rss_content.contains(r#"<podcast:transcript type="text/vtt" url="https://example.com/episodes/3/transcript.vtt">"#));
+    assert!(rss_content.contains(r#"<podcast:chapters type="application/json+chapters" url="https://example.com/episodes/3/chapters.json">"#));
+}
+
+#[test]
+fn test_write_custom_extensions() {
+    let temp_dir = tempdir().unwrap();
+    let content = r#"---
+title: "Test Title"
+pub_date: "2023-09-14T12:34:56Z"
+url: "http://example.com/test"
+team: Platform
+thumbnail:
+  url: "https://example.com/thumb.jpg"
+  width: 640
+---
+"#;
+    fs::write(temp_dir.path().join("test.md"), content).unwrap();
+
+    let rss_conf = RssConf::builder()
+        .title("Custom RSS Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .namespaces([
+            ("acme", "https://example.com/ns/acme"),
+            ("media", "http://search.yahoo.com/mrss/"),
+        ])
+        .extensions([("team", "acme:team"), ("thumbnail", "media:thumbnail")])
+        .build();
+
+    let mut output = Vec::new();
+    write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    let rss_content = String::from_utf8(output).unwrap();
+    assert!(rss_content.contains(r#"xmlns:acme="https://example.com/ns/acme""#));
+    assert!(rss_content.contains(r#"xmlns:media="http://search.yahoo.com/mrss/""#));
+    assert!(rss_content.contains("<acme:team>Platform</acme:team>"));
+    assert!(rss_content
+        .contains(r#"<media:thumbnail url="https://example.com/thumb.jpg" width="640">"#));
+}
+
+#[test]
+fn test_write_dublin_core_and_media() {
+    let temp_dir = tempdir().unwrap();
+    let content = r#"---
+title: "Test Title"
+pub_date: "2023-09-14T12:34:56Z"
+authors: [Alice, Bob]
+tags: [Rust]
+cover_image: cover.png
+enclosure:
+  url: episode.mp3
+---
+"#;
+    // The cover image and the enclosure sit next to the post and are
+    // published in the same directory
+    let posts_dir = temp_dir.path().join("posts");
+    fs::create_dir_all(&posts_dir).unwrap();
+    fs::write(posts_dir.join("test.md"), content).unwrap();
+    fs::write(posts_dir.join("episode.mp3"), [0; 8]).unwrap();
+
+    let rss_conf = RssConf::builder()
+        .title("Custom RSS Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .build();
+
+    let mut output = Vec::new();
+    write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    let rss_content = String::from_utf8(output).unwrap();
+    assert!(rss_content.contains(r#"xmlns:dc="http://purl.org/dc/elements/1.1/""#));
+    assert!(rss_content.contains(r#"xmlns:media="http://search.yahoo.com/mrss/""#));
+    assert!(rss_content.contains("<dc:creator>Alice</dc:creator>"));
+    assert!(rss_content.contains("<dc:creator>Bob</dc:creator>"));
+    assert!(rss_content.contains("<dc:subject>rust</dc:subject>"));
+    assert!(rss_content.contains(
+        r#"<media:content medium="image" type="image/png" url="https://example.com/posts/cover.png">"#
+    ));
+    assert!(rss_content.contains(r#"<media:thumbnail url="https://example.com/posts/cover.png">"#));
+    assert!(rss_content.contains(r#"<enclosure url="https://example.com/posts/episode.mp3""#));
+}
+
+#[test]
+fn test_write_with_author_registry() {
+    let temp_dir = tempdir().unwrap();
+    let registry = r#"
+alice:
+  name: Alice Smith
+  email: alice@example.com
+  avatar: https://example.com/alice.png
+"#;
+    fs::write(temp_dir.path().join("authors.yaml"), registry).unwrap();
+    let known = r#"---
+title: "Known Author"
+pub_date: "2023-09-14T12:34:56Z"
+url: "http://example.com/known"
+author: alice
+---
+"#;
+    let unknown = r#"---
+title: "Unknown Author"
+pub_date: "2023-09-14T12:34:56Z"
+url: "http://example.com/unknown"
+author: mallory
+---
+"#;
+    fs::write(temp_dir.path().join("known.md"), known).unwrap();
+    fs::write(temp_dir.path().join("unknown.md"), unknown).unwrap();
+
+    let rss_conf = RssConf::builder()
+        .title("Custom RSS Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .authors_file("authors.yaml")
+        .build();
+
+    let mut output = Vec::new();
+    let report = write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    assert_eq!(report.item_count, 1);
+    assert!(matches!(
+        &report.failures[..],
+        [MdrssError::UnknownAuthor { handle, .. }] if handle == "mallory"
+    ));
+    let rss_content = String::from_utf8(output).unwrap();
+    assert!(rss_content.contains("<author>alice@example.com (Alice Smith)</author>"));
+    assert!(rss_content.contains("<dc:creator>Alice Smith</dc:creator>"));
+
+    let mut rss_conf = rss_conf;
+    rss_conf.format = FeedFormat::Json;
+    let mut output = Vec::new();
+    write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let author = &json["items"][0]["authors"][0];
+    assert_eq!(author["name"], "Alice Smith");
+    assert_eq!(author["avatar"], "https://example.com/alice.png");
+}
+
+#[test]
+fn test_generate_rss_keeps_output_on_failure() {
+    let temp_dir = tempdir().unwrap();
+    let rss_output_path = temp_dir.path().join("rss.xml");
+    fs::write(&rss_output_path, "previous feed").unwrap();
+
+    let rss_conf = RssConf::builder()
+        .title("Custom RSS Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .authors_file("missing.yaml")
+        .build();
+
+    let err = generate_rss_with_report(
+        temp_dir.path().to_str().unwrap(),
+        rss_output_path.to_str().unwrap(),
+        &rss_conf,
+    )
+    .unwrap_err();
+    assert!(matches!(err, MdrssError::Io { path, .. } if path.ends_with("missing.yaml")));
+    assert_eq!(
+        fs::read_to_string(rss_output_path).unwrap(),
+        "previous feed"
+    );
+}
+
+#[test]
+fn test_write_channel_authors_from_registry() {
+    let temp_dir = tempdir().unwrap();
+    let registry = r#"
+alice:
+  name: Alice Smith
+  email: alice@example.com
+  url: https://alice.example.com
+"#;
+    fs::write(temp_dir.path().join("authors.yaml"), registry).unwrap();
+
+    let mut rss_conf = RssConf::builder()
+        .title("Custom RSS Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .default_author("alice")
+        .authors_file("authors.yaml")
+        .format(FeedFormat::Atom)
+        .build();
+
+    let mut output = Vec::new();
+    write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    let atom_content = String::from_utf8(output).unwrap();
+    assert!(atom_content.contains("<name>Alice Smith</name>"));
+    assert!(atom_content.contains("<email>alice@example.com</email>"));
+    assert!(atom_content.contains("<uri>https://alice.example.com</uri>"));
+    assert!(!atom_content.contains("<name>alice</name>"));
+
+    rss_conf.format = FeedFormat::Rss;
+    rss_conf.podcast = Some(PodcastConf::default());
+    let mut output = Vec::new();
+    write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    let rss_content = String::from_utf8(output).unwrap();
+    assert!(rss_content.contains("<itunes:author>Alice Smith</itunes:author>"));
+
+    let mut podcast = PodcastConf::default();
+    podcast.author = Some(String::from("mallory"));
+    rss_conf.podcast = Some(podcast);
+    let err = write_to(temp_dir.path(), &rss_conf, Vec::new()).unwrap_err();
+    assert!(matches!(err, MdrssError::UnknownAuthor { handle, .. } if handle == "mallory"));
+}
+
+#[test]
+fn test_write_html_description() {
+    let temp_dir = tempdir().unwrap();
+    let content = r#"---
+title: "Test Title"
+pub_date: "2023-09-14T12:34:56Z"
+url: "https://example.com/posts/test/"
+description: 'See <a href="/about">about</a>.'
+---
+"#;
+    fs::write(temp_dir.path().join("test.md"), content).unwrap();
+
+    let mut rss_conf = RssConf::builder()
+        .title("Custom RSS Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .format(FeedFormat::Atom)
+        .build();
+
+    let mut output = Vec::new();
+    write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    let atom_content = String::from_utf8(output).unwrap();
+    assert!(atom_content.contains(r#"<summary type="html">"#));
+    assert!(atom_content.contains("See &lt;a href=&quot;https://example.com/about&quot;&gt;"));
+
+    rss_conf.format = FeedFormat::Json;
+    let mut output = Vec::new();
+    write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(
+        json["items"][0]["content_html"],
+        r#"See <a href="https://example.com/about">about</a>."#
+    );
+    assert!(json["items"][0].get("content_text").is_none());
+}
+
+#[test]
+fn test_write_atom_feed_author_fallback() {
+    let temp_dir = tempdir().unwrap();
+    let content = r#"---
+title: "Anonymous"
+pub_date: "2023-09-14T12:34:56Z"
+---
+"#;
+    fs::write(temp_dir.path().join("anonymous.md"), content).unwrap();
+
+    let rss_conf = RssConf::builder()
+        .title("Custom Atom Title")
+        .link("https://example.com")
+        .description("A test description.")
+        .format(FeedFormat::Atom)
+        .build();
+
+    let mut output = Vec::new();
+    write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    let feed = atom_syndication::Feed::read_from(&output[..]).unwrap();
+    let names = feed
+        .authors()
+        .iter()
+        .map(|author| author.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Custom Atom Title"]);
+    assert!(feed.entries()[0].authors().is_empty());
+
+    fs::write(
+        temp_dir.path().join("anonymous.md"),
+        content.replace("title:", "author: Jane Doe\ntitle:"),
+    )
+    .unwrap();
+    let mut output = Vec::new();
+    write_to(temp_dir.path(), &rss_conf, &mut output).unwrap();
+    let feed = atom_syndication::Feed::read_from(&output[..]).unwrap();
+    assert!(feed.authors().is_empty());
+    assert_eq!(feed.entries()[0].authors()[0].name(), "Jane Doe");
+}